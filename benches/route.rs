@@ -0,0 +1,232 @@
+//! Compares route tree lookups against the matcher the route tree replaced.
+//!
+//! `BaselineMatcher` reproduces the lookup shape of that matcher: dynamic and regex
+//! patterns are bucketed by segment count, the bucket of the request's segment count
+//! is scanned in order and each pattern is matched segment by segment, and tail regex
+//! patterns with fewer segments are tried last.
+
+use std::{
+    collections::HashMap,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use hyperlane::*;
+use regex::Regex;
+
+const ROUTE_COUNT: u32 = 1000;
+
+const ROUNDS: u32 = 10;
+
+struct BenchRoute;
+
+impl ServerHook for BenchRoute {
+    async fn new(_: &mut Stream, _: &mut Context) -> Self {
+        Self
+    }
+
+    async fn handle(self, _: &mut Stream, _: &mut Context) -> Status {
+        Status::Continue
+    }
+}
+
+enum BaselineSegment {
+    Static(String),
+    Dynamic(String),
+    Regex(String, Regex),
+}
+
+struct BaselinePattern(Vec<BaselineSegment>);
+
+#[derive(Default)]
+struct BaselineMatcher {
+    dynamic_route: HashMap<usize, Vec<BaselinePattern>>,
+    regex_route: HashMap<usize, Vec<BaselinePattern>>,
+}
+
+struct BenchCase {
+    name: &'static str,
+    route: fn(u32) -> String,
+    path: fn(u32) -> String,
+}
+
+fn split_path(path: &str) -> Vec<&str> {
+    path.split('/')
+        .filter(|segment: &&str| !segment.is_empty())
+        .collect()
+}
+
+impl BaselinePattern {
+    fn parse(route: &str) -> Self {
+        let segments: Vec<BaselineSegment> = split_path(route)
+            .into_iter()
+            .map(|segment: &str| {
+                let Some(inner) = segment
+                    .strip_prefix('{')
+                    .and_then(|segment: &str| segment.strip_suffix('}'))
+                else {
+                    return BaselineSegment::Static(segment.to_owned());
+                };
+                match inner.split_once(':') {
+                    Some((name, regex)) => {
+                        BaselineSegment::Regex(name.to_owned(), Regex::new(regex).unwrap())
+                    }
+                    None => BaselineSegment::Dynamic(inner.to_owned()),
+                }
+            })
+            .collect();
+        Self(segments)
+    }
+
+    fn has_regex(&self) -> bool {
+        self.0
+            .iter()
+            .any(|segment: &BaselineSegment| matches!(segment, BaselineSegment::Regex(..)))
+    }
+
+    fn has_tail_regex(&self) -> bool {
+        matches!(self.0.last(), Some(BaselineSegment::Regex(..)))
+    }
+
+    fn try_match_path(&self, path: &str) -> Option<RouteParams> {
+        let path_segments: Vec<&str> = split_path(path);
+        let route_segments_len: usize = self.0.len();
+        let is_tail_regex: bool = self.has_tail_regex();
+        if (!is_tail_regex && path_segments.len() != route_segments_len)
+            || (is_tail_regex && path_segments.len() < route_segments_len - 1)
+        {
+            return None;
+        }
+        let mut params: RouteParams = hash_map_xx_hash3_64();
+        for (idx, segment) in self.0.iter().enumerate() {
+            match segment {
+                BaselineSegment::Static(expected_path) => {
+                    if path_segments.get(idx).copied() != Some(expected_path.as_str()) {
+                        return None;
+                    }
+                }
+                BaselineSegment::Dynamic(param_name) => {
+                    params.insert(param_name.clone(), path_segments.get(idx)?.to_string());
+                }
+                BaselineSegment::Regex(param_name, regex) => {
+                    let segment_value: String = if idx == route_segments_len - 1 {
+                        path_segments[idx..].join("/")
+                    } else {
+                        path_segments.get(idx)?.to_string()
+                    };
+                    let found: regex::Match = regex.find(&segment_value)?;
+                    if found.start() != 0 || found.end() != segment_value.len() {
+                        return None;
+                    }
+                    params.insert(param_name.clone(), segment_value);
+                }
+            }
+        }
+        Some(params)
+    }
+}
+
+impl BaselineMatcher {
+    fn add(&mut self, route: &str) {
+        let pattern: BaselinePattern = BaselinePattern::parse(route);
+        let routes: &mut HashMap<usize, Vec<BaselinePattern>> = if pattern.has_regex() {
+            &mut self.regex_route
+        } else {
+            &mut self.dynamic_route
+        };
+        routes.entry(pattern.0.len()).or_default().push(pattern);
+    }
+
+    fn try_resolve_route(&self, path: &str) -> Option<RouteParams> {
+        let path_segment_count: usize = split_path(path).len();
+        for routes in [&self.dynamic_route, &self.regex_route] {
+            if let Some(patterns) = routes.get(&path_segment_count) {
+                for pattern in patterns {
+                    if let Some(params) = pattern.try_match_path(path) {
+                        return Some(params);
+                    }
+                }
+            }
+        }
+        for (&segment_count, patterns) in &self.regex_route {
+            if segment_count >= path_segment_count {
+                continue;
+            }
+            for pattern in patterns {
+                if pattern.has_tail_regex()
+                    && let Some(params) = pattern.try_match_path(path)
+                {
+                    return Some(params);
+                }
+            }
+        }
+        None
+    }
+}
+
+fn bench_tree(case: &BenchCase) -> Duration {
+    let mut server: Server = Server::default();
+    for i in 0..ROUTE_COUNT {
+        server.route::<BenchRoute>((case.route)(i));
+    }
+    let route_matcher: &RouteMatcher = server.get_route_matcher();
+    route_matcher.prepare();
+    let paths: Vec<String> = (0..ROUTE_COUNT).map(case.path).collect();
+    let mut ctx: Context = Context::default();
+    let start: Instant = Instant::now();
+    for _ in 0..ROUNDS {
+        for path in &paths {
+            assert!(black_box(route_matcher.try_resolve_route(&mut ctx, path)).is_some());
+        }
+    }
+    start.elapsed() / (ROUTE_COUNT * ROUNDS)
+}
+
+fn bench_baseline(case: &BenchCase) -> Duration {
+    let mut baseline_matcher: BaselineMatcher = BaselineMatcher::default();
+    for i in 0..ROUTE_COUNT {
+        baseline_matcher.add(&(case.route)(i));
+    }
+    let paths: Vec<String> = (0..ROUTE_COUNT).map(case.path).collect();
+    let start: Instant = Instant::now();
+    for _ in 0..ROUNDS {
+        for path in &paths {
+            assert!(black_box(baseline_matcher.try_resolve_route(path)).is_some());
+        }
+    }
+    start.elapsed() / (ROUTE_COUNT * ROUNDS)
+}
+
+fn main() {
+    let cases: [BenchCase; 4] = [
+        BenchCase {
+            name: "dynamic",
+            route: |i: u32| format!("/api/resource{i}/{{id}}"),
+            path: |i: u32| format!("/api/resource{i}/123"),
+        },
+        BenchCase {
+            name: "regex",
+            route: |i: u32| format!("/api/resource{i}/{{id:[0-9]+}}"),
+            path: |i: u32| format!("/api/resource{i}/123"),
+        },
+        BenchCase {
+            name: "tail regex",
+            route: |i: u32| format!("/files{i}/{{path:.*}}"),
+            path: |i: u32| format!("/files{i}/a/b/c.txt"),
+        },
+        BenchCase {
+            name: "sibling regex",
+            route: |i: u32| format!("/api/{{id{i}:item{i}-[0-9]+}}"),
+            path: |i: u32| format!("/api/item{i}-123"),
+        },
+    ];
+    println!("Average match time over {ROUTE_COUNT} routes");
+    for case in &cases {
+        println!(
+            "{:<14} tree: {:>10?}  baseline: {:>10?}",
+            case.name,
+            bench_tree(case),
+            bench_baseline(case)
+        );
+    }
+}
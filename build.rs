@@ -0,0 +1,18 @@
+use std::env;
+
+/// The target operating systems of the Unix family without `SO_REUSEPORT` support in `socket2`.
+const REUSEPORT_UNSUPPORTED_TARGET_OS: [&str; 4] = ["solaris", "illumos", "cygwin", "nuttx"];
+
+/// Emits the `reuseport` cfg on platforms where listeners can share an address with `SO_REUSEPORT`.
+fn main() {
+    println!("cargo::rustc-check-cfg=cfg(reuseport)");
+    let target_family: String = env::var("CARGO_CFG_TARGET_FAMILY").unwrap_or_default();
+    let target_os: String = env::var("CARGO_CFG_TARGET_OS").unwrap_or_default();
+    if target_family
+        .split(',')
+        .any(|family: &str| family == "unix")
+        && !REUSEPORT_UNSUPPORTED_TARGET_OS.contains(&target_os.as_str())
+    {
+        println!("cargo::rustc-cfg=reuseport");
+    }
+}
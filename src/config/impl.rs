@@ -11,8 +11,26 @@ impl Default for ServerConfig {
     fn default() -> Self {
         Self {
             address: Server::format_bind_address(DEFAULT_HOST, DEFAULT_WEB_PORT),
+            additional_addresses: Vec::new(),
             nodelay: DEFAULT_NODELAY,
             ttl: DEFAULT_TTI,
+            backlog: None,
+            reuseaddr: None,
+            ipv6_only: None,
+            fastopen: None,
+            keepalive_idle_ms: None,
+            keepalive_interval_ms: None,
+            keepalive_retries: None,
+            recv_buffer_size: None,
+            send_buffer_size: None,
+            linger_secs: None,
+            shutdown_timeout_ms: None,
+            keep_alive_timeout_ms: None,
+            request_header_timeout_ms: None,
+            request_read_timeout_ms: None,
+            max_connections: None,
+            proxy_protocol: None,
+            acceptors: None,
         }
     }
 }
@@ -34,4 +52,81 @@ impl ServerConfig {
     {
         serde_json::from_str(json.as_ref())
     }
+
+    /// Adds an address the server binds to alongside `address`.
+    ///
+    /// # Arguments
+    ///
+    /// - `AsRef<str>` - The address to bind to.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Self` - Reference to self for method chaining.
+    #[inline(always)]
+    pub fn add_address<A>(&mut self, address: A) -> &mut Self
+    where
+        A: AsRef<str>,
+    {
+        self.get_mut_additional_addresses()
+            .push(address.as_ref().to_owned());
+        self
+    }
+
+    /// Builds the TCP keepalive parameters from the keepalive options.
+    ///
+    /// The probe interval and count are only applied on platforms supporting them.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<TcpKeepalive>` - The keepalive parameters, or `None` if no keepalive option is set.
+    #[inline]
+    pub fn try_get_tcp_keepalive(&self) -> Option<TcpKeepalive> {
+        if self.try_get_keepalive_idle_ms().is_none()
+            && self.try_get_keepalive_interval_ms().is_none()
+            && self.try_get_keepalive_retries().is_none()
+        {
+            return None;
+        }
+        let mut keepalive: TcpKeepalive = TcpKeepalive::new();
+        if let Some(keepalive_idle_ms) = self.try_get_keepalive_idle_ms() {
+            keepalive = keepalive.with_time(Duration::from_millis(*keepalive_idle_ms));
+        }
+        #[cfg(any(
+            target_os = "android",
+            target_os = "freebsd",
+            target_os = "ios",
+            target_os = "linux",
+            target_os = "macos",
+            target_os = "netbsd",
+            target_os = "windows",
+        ))]
+        if let Some(keepalive_interval_ms) = self.try_get_keepalive_interval_ms() {
+            keepalive = keepalive.with_interval(Duration::from_millis(*keepalive_interval_ms));
+        }
+        #[cfg(any(
+            target_os = "android",
+            target_os = "freebsd",
+            target_os = "ios",
+            target_os = "linux",
+            target_os = "macos",
+            target_os = "netbsd",
+            target_os = "windows",
+        ))]
+        if let Some(keepalive_retries) = self.try_get_keepalive_retries() {
+            keepalive = keepalive.with_retries(*keepalive_retries);
+        }
+        Some(keepalive)
+    }
+
+    /// Returns every address the server binds to.
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<&String>` - `address` followed by the additional addresses.
+    #[inline(always)]
+    pub fn get_bind_addresses(&self) -> Vec<&String> {
+        std::iter::once(self.get_address())
+            .chain(self.get_additional_addresses().iter())
+            .collect()
+    }
 }
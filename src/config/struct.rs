@@ -9,8 +9,81 @@ pub struct ServerConfig {
     /// The address the server will bind to.
     #[set(type(AsRef<str>))]
     pub(super) address: String,
+    /// Further addresses the server binds to alongside `address`.
+    #[serde(default)]
+    #[new(skip)]
+    pub(super) additional_addresses: Vec<String>,
     /// The `TCP_NODELAY` option for sockets.
     pub(super) nodelay: Option<bool>,
     /// The `IP_TTL` option for sockets.
     pub(super) ttl: Option<u32>,
+    /// The listen backlog of the listeners.
+    #[new(skip)]
+    pub(super) backlog: Option<u32>,
+    /// The `SO_REUSEADDR` option for listeners.
+    #[new(skip)]
+    pub(super) reuseaddr: Option<bool>,
+    /// The `IPV6_V6ONLY` option for listeners bound to an IPv6 address.
+    ///
+    /// `None` enables it, so that IPv4 and IPv6 wildcard addresses can share a port.
+    #[new(skip)]
+    pub(super) ipv6_only: Option<bool>,
+    /// The `TCP_FASTOPEN` queue length for listeners. Only applied on Linux and Android.
+    #[new(skip)]
+    pub(super) fastopen: Option<u32>,
+    /// The idle time in milliseconds before TCP keepalive probes are sent.
+    ///
+    /// TCP keepalive is enabled when any keepalive option is set.
+    #[new(skip)]
+    pub(super) keepalive_idle_ms: Option<u64>,
+    /// The interval in milliseconds between TCP keepalive probes.
+    #[new(skip)]
+    pub(super) keepalive_interval_ms: Option<u64>,
+    /// The number of unanswered TCP keepalive probes before the connection is dropped.
+    #[new(skip)]
+    pub(super) keepalive_retries: Option<u32>,
+    /// The `SO_RCVBUF` option for sockets.
+    #[new(skip)]
+    pub(super) recv_buffer_size: Option<usize>,
+    /// The `SO_SNDBUF` option for sockets.
+    #[new(skip)]
+    pub(super) send_buffer_size: Option<usize>,
+    /// The `SO_LINGER` timeout for accepted connections, in seconds.
+    ///
+    /// `SO_LINGER` only has whole-second precision. `0` makes closing a connection
+    /// reset it, discarding any response bytes not yet sent.
+    #[new(skip)]
+    pub(super) linger_secs: Option<u64>,
+    /// The time in milliseconds to wait for in-flight connections after shutdown.
+    ///
+    /// Connections still open when it elapses are closed. `None` waits until every connection finishes.
+    #[new(skip)]
+    pub(super) shutdown_timeout_ms: Option<u64>,
+    /// The time in milliseconds a keep-alive connection may stay idle between requests.
+    #[new(skip)]
+    pub(super) keep_alive_timeout_ms: Option<u64>,
+    /// The time in milliseconds allowed to receive a request head, counted from accept on a new
+    /// connection and from the first byte of the request on a keep-alive connection.
+    #[new(skip)]
+    pub(super) request_header_timeout_ms: Option<u64>,
+    /// The time in milliseconds allowed to read a request, overriding the `read_timeout_ms`
+    /// of the `RequestConfig`.
+    #[new(skip)]
+    pub(super) request_read_timeout_ms: Option<u64>,
+    /// The number of listeners opened per address with `SO_REUSEPORT`, each with its own accept loop.
+    ///
+    /// `0` opens one per Tokio worker thread. `None` opens a single listener. Platforms
+    /// without `SO_REUSEPORT` always open a single listener.
+    #[new(skip)]
+    pub(super) acceptors: Option<usize>,
+    /// Whether every connection must start with a PROXY protocol v1 or v2 header.
+    ///
+    /// Connections whose header is missing or malformed are closed.
+    #[new(skip)]
+    pub(super) proxy_protocol: Option<bool>,
+    /// The maximum number of connections served at once.
+    ///
+    /// Accepting pauses while the limit is reached. `None` or `0` accepts without limit.
+    #[new(skip)]
+    pub(super) max_connections: Option<usize>,
 }
@@ -14,6 +14,9 @@ impl Default for Context {
             response: Response::default(),
             route_params: RouteParams::default(),
             attributes: ThreadSafeAttributeStore::default(),
+            client_address: None,
+            destination_address: None,
+            connection_timeout: None,
         }
     }
 }
@@ -36,6 +39,9 @@ impl PartialEq for Context {
             && self.get_response() == other.get_response()
             && self.get_route_params() == other.get_route_params()
             && self.get_attributes().len() == other.get_attributes().len()
+            && self.try_get_client_address() == other.try_get_client_address()
+            && self.try_get_destination_address() == other.try_get_destination_address()
+            && self.try_get_connection_timeout() == other.try_get_connection_timeout()
     }
 }
 
@@ -15,4 +15,13 @@ pub struct Context {
     pub(super) route_params: RouteParams,
     /// A collection of custom attributes for sharing data within the request lifecycle.
     pub(super) attributes: ThreadSafeAttributeStore,
+    /// The original client address announced by a PROXY protocol header.
+    #[set(pub(crate))]
+    pub(super) client_address: Option<SocketAddr>,
+    /// The original destination address announced by a PROXY protocol header.
+    #[set(pub(crate))]
+    pub(super) destination_address: Option<SocketAddr>,
+    /// The connection timeout that elapsed, if the connection was closed by one.
+    #[set(pub(crate))]
+    pub(super) connection_timeout: Option<ConnectionTimeout>,
 }
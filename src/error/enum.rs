@@ -5,6 +5,8 @@ use super::*;
 pub enum ServerError {
     /// An error occurred while trying to bind to a TCP socket.
     TcpBind(String),
+    /// The bind address uses a transport the server cannot serve, such as a Unix socket.
+    UnsupportedAddress(String),
     /// An unknown or unexpected error occurred.
     Unknown(String),
     /// An error occurred while reading an HTTP request.
@@ -15,6 +17,49 @@ pub enum ServerError {
     Other(String),
 }
 
+/// Represents errors returned while accepting connections, grouped by how the
+/// accept loop reacts to them.
+#[derive(Clone, CustomDebug, Deserialize, DisplayDebug, Eq, Hash, PartialEq, Serialize)]
+pub enum AcceptError {
+    /// An error tied to a single connection. Accepting continues immediately.
+    Transient(String),
+    /// The process or system ran out of file descriptors, buffers or memory.
+    /// Accepting continues after a bounded exponential backoff.
+    ResourceExhausted(String),
+    /// The listener itself is unusable. Accepting stops and the server shuts down.
+    Fatal(String),
+    /// The PROXY protocol header of an accepted connection was rejected.
+    /// The connection is closed and accepting continues.
+    ProxyProtocol(ProxyProtocolError),
+}
+
+/// Represents errors while reading a PROXY protocol header.
+#[derive(Clone, CustomDebug, Deserialize, DisplayDebug, Eq, Hash, PartialEq, Serialize)]
+pub enum ProxyProtocolError {
+    /// The connection did not start with a PROXY protocol header.
+    Missing,
+    /// The PROXY protocol header is malformed.
+    Malformed(String),
+    /// The PROXY protocol header did not arrive in time.
+    Timeout,
+    /// Reading the PROXY protocol header failed.
+    Io(String),
+}
+
+/// Represents the connection timeouts enforced by the server.
+///
+/// When one of them elapses, the request error hooks run with a `ReadTimeout`
+/// request error and the kind is available through `Context::try_get_connection_timeout`.
+#[derive(Clone, Copy, CustomDebug, Deserialize, DisplayDebug, Eq, Hash, PartialEq, Serialize)]
+pub enum ConnectionTimeout {
+    /// No new request arrived on a keep-alive connection in time.
+    KeepAlive,
+    /// The head of a request was not received in time.
+    RequestHeader,
+    /// A request was not read completely within the read timeout.
+    RequestRead,
+}
+
 /// Represents errors related to route definitions and matching.
 #[derive(Clone, CustomDebug, Deserialize, DisplayDebug, Eq, PartialEq, Serialize)]
 pub enum RouteError {
@@ -24,4 +69,7 @@ pub enum RouteError {
     DuplicatePattern(String),
     /// The provided route pattern is not a valid regular expression.
     InvalidRegexPattern(String),
+    /// The catch-all segment is unnamed, has a regex, or is not the last segment of the
+    /// route pattern.
+    InvalidCatchAllPattern(String),
 }
@@ -19,3 +19,60 @@ impl From<std::io::Error> for ServerError {
         ServerError::TcpBind(error.to_string())
     }
 }
+
+/// Implementation of `From` trait for classifying accept errors.
+///
+/// Running out of file descriptors, buffers or memory is `ResourceExhausted`, a
+/// listener that is not a valid listening socket is `Fatal`, and every other error
+/// only affects the connection being accepted and is `Transient`.
+impl From<&std::io::Error> for AcceptError {
+    /// Classifies an error returned by `TcpListener::accept`.
+    ///
+    /// # Arguments
+    ///
+    /// - `&IoError` - The error returned while accepting.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - The classified `AcceptError`.
+    #[inline]
+    fn from(error: &std::io::Error) -> Self {
+        let message: String = error.to_string();
+        #[cfg(unix)]
+        match error.raw_os_error() {
+            Some(libc::EMFILE | libc::ENFILE | libc::ENOBUFS | libc::ENOMEM) => {
+                return AcceptError::ResourceExhausted(message);
+            }
+            Some(libc::EBADF | libc::ENOTSOCK | libc::EINVAL | libc::EOPNOTSUPP) => {
+                return AcceptError::Fatal(message);
+            }
+            _ => {}
+        }
+        #[cfg(windows)]
+        if let Some(WSAEMFILE | WSAENOBUFS) = error.raw_os_error() {
+            return AcceptError::ResourceExhausted(message);
+        }
+        match error.kind() {
+            ErrorKind::OutOfMemory => AcceptError::ResourceExhausted(message),
+            ErrorKind::InvalidInput => AcceptError::Fatal(message),
+            _ => AcceptError::Transient(message),
+        }
+    }
+}
+
+/// Implementation of `From` trait for converting read errors into `ProxyProtocolError`.
+impl From<std::io::Error> for ProxyProtocolError {
+    /// Creates a new `ProxyProtocolError::Io` instance from a `IoError`.
+    ///
+    /// # Arguments
+    ///
+    /// - `IoError` - The `IoError` to convert.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - A new `ProxyProtocolError::Io` instance.
+    #[inline(always)]
+    fn from(error: std::io::Error) -> Self {
+        ProxyProtocolError::Io(error.to_string())
+    }
+}
@@ -28,9 +28,30 @@ pub enum HookType {
     /// - `&'static str` - The route path pattern handled by this hook.
     /// - `ServerHookHandlerFactory` - Factory function creating the route handler.
     Route(&'static str, ServerHookHandlerFactory),
+    /// Hook representing a route handler for a specific HTTP method and path.
+    ///
+    /// - `&'static str` - The HTTP method name handled by this hook, such as `GET` or `POST`.
+    /// - `&'static str` - The route path pattern handled by this hook.
+    /// - `ServerHookHandlerFactory` - Factory function creating the route handler.
+    RouteMethod(&'static str, &'static str, ServerHookHandlerFactory),
+    /// Hook triggered when no route matches the request path.
+    ///
+    /// - `Option<isize>` - Optional execution priority. Higher values execute first.
+    /// - `ServerHookHandlerFactory` - Factory function creating the not found handler.
+    NotFound(Option<isize>, ServerHookHandlerFactory),
+    /// Hook triggered when a path matches a route but not the request method.
+    ///
+    /// - `Option<isize>` - Optional execution priority. Higher values execute first.
+    /// - `ServerHookHandlerFactory` - Factory function creating the method not allowed handler.
+    MethodNotAllowed(Option<isize>, ServerHookHandlerFactory),
     /// Hook executed after a route handler but before the response is sent.
     ///
     /// - `Option<isize>` - Optional execution priority. Higher values execute first.
     /// - `ServerHookHandlerFactory` - Factory function creating the middleware handler.
     ResponseMiddleware(Option<isize>, ServerHookHandlerFactory),
+    /// Hook triggered when accepting a connection fails.
+    ///
+    /// - `Option<isize>` - Optional execution priority. Higher values execute first.
+    /// - `AcceptErrorHookHandlerFactory` - Factory function creating the accept error handler.
+    AcceptError(Option<isize>, AcceptErrorHookHandlerFactory),
 }
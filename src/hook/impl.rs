@@ -53,6 +53,8 @@ impl Default for ServerControlHook {
         Self {
             wait_hook: Hook::default_control_handler(),
             shutdown_hook: Hook::default_control_handler(),
+            connection_tracker: ConnectionTracker::default(),
+            local_addresses: Vec::new(),
         }
     }
 }
@@ -76,6 +78,40 @@ impl ServerControlHook {
     pub async fn shutdown(&self) {
         self.get_shutdown_hook()().await;
     }
+
+    /// Returns the number of connections the server is currently serving.
+    ///
+    /// # Returns
+    ///
+    /// - `usize` - The number of active connections.
+    #[inline(always)]
+    pub fn connection_count(&self) -> usize {
+        self.get_connection_tracker().connection_count()
+    }
+
+    /// Returns the local address of the server's first listener.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<SocketAddr>` - The bound address, or `None` if the server is not running.
+    #[inline(always)]
+    pub fn try_get_local_address(&self) -> Option<SocketAddr> {
+        self.get_local_addresses().first().copied()
+    }
+
+    /// Returns the local address of the server's first listener.
+    ///
+    /// # Returns
+    ///
+    /// - `SocketAddr` - The bound address.
+    ///
+    /// # Panics
+    ///
+    /// - If the server is not running.
+    #[inline(always)]
+    pub fn get_local_address(&self) -> SocketAddr {
+        self.try_get_local_address().unwrap()
+    }
 }
 
 /// Factory and utility functions for creating hook handlers.
@@ -134,6 +170,43 @@ impl Hook {
             },
         )
     }
+
+    /// Creates a new `AcceptErrorHookHandler` from a trait object.
+    ///
+    /// # Arguments
+    ///
+    /// - `AcceptErrorHook` - The trait object implementing `AcceptErrorHook`.
+    ///
+    /// # Returns
+    ///
+    /// - `AcceptErrorHookHandler` - A new `AcceptErrorHookHandler` instance.
+    #[inline(always)]
+    pub fn accept_error_factory<R>() -> AcceptErrorHookHandler
+    where
+        R: AcceptErrorHook,
+    {
+        Arc::new(|error: &AcceptError| -> FutureBox<Status> {
+            let error: AcceptError = error.clone();
+            Box::pin(async move { R::new(&error).await.handle(&error).await })
+        })
+    }
+
+    /// Inserts a hook into a list while keeping it sorted by descending priority.
+    ///
+    /// A missing priority is treated as `0`. Hooks with equal priority keep their
+    /// registration order.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut Vec<(isize, H)>` - The hook list to insert into.
+    /// - `Option<isize>` - The priority of the hook, higher values execute first.
+    /// - `H` - The hook handler to insert.
+    #[inline(always)]
+    pub fn insert_by_order<H>(list: &mut Vec<(isize, H)>, order: Option<isize>, hook: H) {
+        let order: isize = order.unwrap_or_default();
+        let index: usize = list.partition_point(|(current, _)| *current >= order);
+        list.insert(index, (order, hook));
+    }
 }
 
 /// Implements the `PartialEq` trait for `HookType`.
@@ -167,10 +240,24 @@ impl PartialEq for HookType {
             (HookType::Route(path1, factory1), HookType::Route(path2, factory2)) => {
                 path1 == path2 && std::ptr::fn_addr_eq(*factory1, *factory2)
             }
+            (
+                HookType::RouteMethod(method1, path1, factory1),
+                HookType::RouteMethod(method2, path2, factory2),
+            ) => method1 == method2 && path1 == path2 && std::ptr::fn_addr_eq(*factory1, *factory2),
+            (HookType::NotFound(order1, factory1), HookType::NotFound(order2, factory2)) => {
+                order1 == order2 && std::ptr::fn_addr_eq(*factory1, *factory2)
+            }
+            (
+                HookType::MethodNotAllowed(order1, factory1),
+                HookType::MethodNotAllowed(order2, factory2),
+            ) => order1 == order2 && std::ptr::fn_addr_eq(*factory1, *factory2),
             (
                 HookType::ResponseMiddleware(order1, factory1),
                 HookType::ResponseMiddleware(order2, factory2),
             ) => order1 == order2 && std::ptr::fn_addr_eq(*factory1, *factory2),
+            (HookType::AcceptError(order1, factory1), HookType::AcceptError(order2, factory2)) => {
+                order1 == order2 && std::ptr::fn_addr_eq(*factory1, *factory2)
+            }
             _ => false,
         }
     }
@@ -219,6 +306,27 @@ impl Hash for HookType {
                 order.hash(state);
                 (factory as *const fn() -> ServerHookHandler).hash(state);
             }
+            HookType::RouteMethod(method, path, factory) => {
+                5u8.hash(state);
+                method.hash(state);
+                path.hash(state);
+                (factory as *const fn() -> ServerHookHandler).hash(state);
+            }
+            HookType::MethodNotAllowed(order, factory) => {
+                6u8.hash(state);
+                order.hash(state);
+                (factory as *const fn() -> ServerHookHandler).hash(state);
+            }
+            HookType::NotFound(order, factory) => {
+                7u8.hash(state);
+                order.hash(state);
+                (factory as *const fn() -> ServerHookHandler).hash(state);
+            }
+            HookType::AcceptError(order, factory) => {
+                8u8.hash(state);
+                order.hash(state);
+                (factory as *const fn() -> AcceptErrorHookHandler).hash(state);
+            }
         }
     }
 }
@@ -243,7 +351,10 @@ impl HookType {
             HookType::RequestMiddleware(order, _)
             | HookType::ResponseMiddleware(order, _)
             | HookType::TaskPanic(order, _)
-            | HookType::RequestError(order, _) => order,
+            | HookType::RequestError(order, _)
+            | HookType::NotFound(order, _)
+            | HookType::MethodNotAllowed(order, _)
+            | HookType::AcceptError(order, _) => order,
             _ => None,
         }
     }
@@ -254,7 +365,9 @@ impl HookType {
             HookType::RequestMiddleware(_, hook)
             | HookType::ResponseMiddleware(_, hook)
             | HookType::TaskPanic(_, hook)
-            | HookType::RequestError(_, hook) => Some(hook),
+            | HookType::RequestError(_, hook)
+            | HookType::NotFound(_, hook)
+            | HookType::MethodNotAllowed(_, hook) => Some(hook),
             _ => None,
         }
     }
@@ -40,9 +40,9 @@ pub struct Hook;
 /// Represents the hooks for managing the server's lifecycle, specifically for waiting and shutting down.
 #[derive(Clone, CustomDebug, DisplayDebug, Getter, Setter)]
 pub struct ServerControlHook {
-    /// A hook that returns a future, which completes when the server's main task finishes.
-    /// This is typically used to wait for the server to stop accepting connections before
-    /// the application exits.
+    /// A hook that returns a future, which completes once the server has stopped accepting
+    /// connections and drained the open ones. This is typically used to wait for in-flight
+    /// requests to finish before the application exits.
     #[debug(skip)]
     #[set(pub(crate))]
     pub(super) wait_hook: ServerControlHookHandler<()>,
@@ -52,4 +52,12 @@ pub struct ServerControlHook {
     #[debug(skip)]
     #[set(pub(crate))]
     pub(super) shutdown_hook: ServerControlHookHandler<()>,
+    /// The tracker of the connections served by the server.
+    #[set(pub(crate))]
+    pub(super) connection_tracker: ConnectionTracker,
+    /// The local addresses the server's listeners are bound to.
+    ///
+    /// They carry the actual port when the server was bound to port `0`.
+    #[set(pub(crate))]
+    pub(super) local_addresses: Vec<SocketAddr>,
 }
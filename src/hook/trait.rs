@@ -82,3 +82,31 @@ pub trait ServerHook: Send + Sync + 'static {
     /// - `Status` - `Status::Continue` if the pipeline should proceed, `Status::Reject` if the pipeline should be aborted.
     fn handle(self, stream: &mut Stream, ctx: &mut Context) -> impl Future<Output = Status> + Send;
 }
+
+/// Trait for hooks that observe errors returned while accepting connections.
+///
+/// Accept errors happen before any connection exists, so unlike `ServerHook` these
+/// hooks receive the classified `AcceptError` instead of a `Stream` and `Context`.
+pub trait AcceptErrorHook: Send + Sync + 'static {
+    /// Creates a new instance of this hook from the accept error.
+    ///
+    /// # Arguments
+    ///
+    /// - `&AcceptError` - The error returned while accepting.
+    ///
+    /// # Returns
+    ///
+    /// A future that resolves to a new instance of this hook.
+    fn new(error: &AcceptError) -> impl Future<Output = Self> + Send;
+
+    /// Executes the hook's processing logic.
+    ///
+    /// # Arguments
+    ///
+    /// - `&AcceptError` - The error returned while accepting.
+    ///
+    /// # Returns
+    ///
+    /// - `Status` - `Status::Continue` to run the next accept error hook, `Status::Reject` to skip the rest.
+    fn handle(self, error: &AcceptError) -> impl Future<Output = Status> + Send;
+}
@@ -38,18 +38,41 @@ pub type ServerHookHandlerFactory = fn() -> ServerHookHandler;
 pub type ServerHookHandler =
     Arc<dyn Fn(&mut Stream, &mut Context) -> FutureBox<Status> + Send + Sync>;
 
-/// Type alias for a list of server hook handlers.
+/// A type alias for an accept error hook handler factory function.
+///
+/// This function pointer type is used to create AcceptErrorHookHandler instances
+/// based on generic types. It allows delayed instantiation of handlers.
+pub type AcceptErrorHookHandlerFactory = fn() -> AcceptErrorHookHandler;
+
+/// Type alias for a shared accept error hook handler.
+///
+/// The handler takes the classified `AcceptError` and returns a pinned, boxed
+/// future that resolves to `Status`.
+pub type AcceptErrorHookHandler = Arc<dyn Fn(&AcceptError) -> FutureBox<Status> + Send + Sync>;
+
+/// Type alias for a list of accept error hook handlers paired with their priority.
+///
+/// The list is kept sorted by descending priority so that higher values execute first.
+pub type AcceptErrorHookList = Vec<(isize, AcceptErrorHookHandler)>;
+
+/// Type alias for a list of server hook handlers paired with their priority.
 ///
 /// Used to store middleware handlers in the request/response processing pipeline.
-pub type ServerHookList = Vec<ServerHookHandler>;
+/// The list is kept sorted by descending priority so that higher values execute first.
+pub type ServerHookList = Vec<(isize, ServerHookHandler)>;
+
+/// Type alias for a list of server hook handlers bound to HTTP methods.
+///
+/// Used to dispatch a single route pattern to different handlers per method.
+pub type ServerHookMethodList = Vec<(Method, ServerHookHandler)>;
 
 /// Type alias for a map of server route handlers.
 ///
 /// Used for fast lookup of exact-match routes.
-pub type ServerHookMap = HashMapXxHash3_64<String, ServerHookHandler>;
+pub type ServerHookMap = HashMapXxHash3_64<String, RouteHandler>;
 
 /// Type alias for a collection of pattern-based server hook route grouped by segment count.
 ///
-/// The outer HashMap uses segment count as key for fast filtering.
-/// The inner Vec stores patterns with the same segment count, maintaining insertion order.
-pub type ServerHookPatternRoute = HashMapXxHash3_64<usize, Vec<(RoutePattern, ServerHookHandler)>>;
+/// The outer HashMap uses segment count as key.
+/// The inner Vec stores the patterns with the same segment count.
+pub type ServerHookPatternRoute = HashMapXxHash3_64<usize, Vec<(RoutePattern, RouteHandler)>>;
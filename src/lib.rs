@@ -25,20 +25,29 @@ use std::{
     collections::HashSet,
     future::Future,
     hash::{Hash, Hasher},
-    io::{self, Write, stderr, stdout},
+    io::{self, ErrorKind, Write, stderr, stdout},
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr},
     pin::Pin,
-    sync::Arc,
+    sync::{Arc, OnceLock},
+    time::Duration,
 };
 
 use {
     inventory::collect,
     lombok_macros::*,
-    regex::Regex,
+    regex::{Regex, RegexSet},
     serde::{Deserialize, Serialize},
+    socket2::{Domain, SockRef, Socket, TcpKeepalive, Type},
     tokio::{
-        net::{TcpListener, TcpStream},
-        spawn,
-        sync::watch::{Receiver, Sender, channel},
+        io::AsyncReadExt,
+        net::{TcpListener, TcpStream, lookup_host},
+        runtime::Handle,
+        select, spawn,
+        sync::{
+            OwnedSemaphorePermit, Semaphore,
+            watch::{Receiver, Sender, channel, error::RecvError},
+        },
         task::JoinHandle,
+        time::{Instant, sleep, timeout, timeout_at},
     },
 };
@@ -0,0 +1,2 @@
+/// The prefix marking a catch-all segment inside brackets, as in `{*rest}`.
+pub const CATCH_ALL_PREFIX: &str = "*";
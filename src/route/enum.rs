@@ -19,4 +19,19 @@ pub enum RouteSegment {
     /// This allows for more complex and flexible routing logic. The first element is the parameter
     /// name, and the second is the compiled `Regex` object.
     Regex(String, Regex),
+    /// A catch-all segment that captures the rest of the path.
+    /// It is denoted by `{*name}`, must be the last segment, and matches zero or more
+    /// remaining segments. The captured remainder is stored under the parameter name.
+    CatchAll(String),
+}
+
+/// The compiled regexes of the regex children of a route node.
+#[derive(Clone, CustomDebug, DisplayDebug)]
+pub(crate) enum RegexChildMatcher {
+    /// The anchored regexes of every child compiled together, matched in a single pass.
+    /// Pattern indices match the order of the regex children.
+    Set(RegexSet),
+    /// The anchored regex of each child paired with its index, tried one after another.
+    /// It is used when the combined set exceeds the regex size limit.
+    List(Vec<(usize, Regex)>),
 }
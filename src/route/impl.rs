@@ -9,13 +9,32 @@ impl Default for RouteMatcher {
     ///
     /// # Returns
     ///
-    /// - `RouteMatcher` - A new RouteMatcher with empty storage for static, dynamic, and regex route.
+    /// - `RouteMatcher` - A new RouteMatcher with an empty static route map and route tree.
     #[inline(always)]
     fn default() -> Self {
         Self {
             static_route: hash_map_xx_hash3_64(),
-            dynamic_route: hash_map_xx_hash3_64(),
-            regex_route: hash_map_xx_hash3_64(),
+            route_tree: RouteNode::default(),
+        }
+    }
+}
+
+/// Provides a default implementation for RouteNode.
+impl Default for RouteNode {
+    /// Creates a new node without children or hooks.
+    ///
+    /// # Returns
+    ///
+    /// - `RouteNode` - A new, empty RouteNode.
+    #[inline(always)]
+    fn default() -> Self {
+        Self {
+            static_children: hash_map_xx_hash3_64(),
+            dynamic_children: Vec::new(),
+            regex_children: Vec::new(),
+            regex_matcher: OnceLock::new(),
+            catch_all_children: Vec::new(),
+            handler: None,
         }
     }
 }
@@ -119,43 +138,7 @@ impl PartialEq for RouteMatcher {
                 return false;
             }
         }
-        if self.get_dynamic_route().len() != other.get_dynamic_route().len() {
-            return false;
-        }
-        for (segment_count, routes) in self.get_dynamic_route() {
-            match other.get_dynamic_route().get(segment_count) {
-                Some(other_routes) if routes.len() == other_routes.len() => {
-                    for (pattern, _) in routes {
-                        if !other_routes
-                            .iter()
-                            .any(|entry: &(RoutePattern, ServerHookHandler)| &entry.0 == pattern)
-                        {
-                            return false;
-                        }
-                    }
-                }
-                _ => return false,
-            }
-        }
-        if self.get_regex_route().len() != other.get_regex_route().len() {
-            return false;
-        }
-        for (segment_count, routes) in self.get_regex_route() {
-            match other.get_regex_route().get(segment_count) {
-                Some(other_routes) if routes.len() == other_routes.len() => {
-                    for (pattern, _) in routes {
-                        if !other_routes
-                            .iter()
-                            .any(|entry: &(RoutePattern, ServerHookHandler)| &entry.0 == pattern)
-                        {
-                            return false;
-                        }
-                    }
-                }
-                _ => return false,
-            }
-        }
-        true
+        self.get_route_tree() == other.get_route_tree()
     }
 }
 
@@ -164,6 +147,39 @@ impl PartialEq for RouteMatcher {
 /// This indicates that `RouteMatcher` has a total equality relation.
 impl Eq for RouteMatcher {}
 
+/// Implements the `PartialEq` trait for `RouteNode`.
+///
+/// Two nodes are equal when they hold the same route patterns, whatever their hooks.
+impl PartialEq for RouteNode {
+    /// Checks if two `RouteNode` instances are equal.
+    ///
+    /// # Arguments
+    ///
+    /// - `&Self`- The other `RouteNode` instance to compare against.
+    ///
+    /// # Returns
+    ///
+    /// - `bool`- `true` if the instances are equal, `false` otherwise.
+    fn eq(&self, other: &Self) -> bool {
+        self.handler.is_some() == other.handler.is_some()
+            && self.static_children.len() == other.static_children.len()
+            && self
+                .static_children
+                .iter()
+                .all(|(segment, child): (&String, &RouteNode)| {
+                    other.static_children.get(segment) == Some(child)
+                })
+            && self.dynamic_children == other.dynamic_children
+            && self.regex_children == other.regex_children
+            && self.catch_all_children == other.catch_all_children
+    }
+}
+
+/// Implements the `Eq` trait for `RouteNode`.
+///
+/// This indicates that `RouteNode` has a total equality relation.
+impl Eq for RouteNode {}
+
 /// Implements the `Eq` trait for `RouteSegment`.
 ///
 /// This indicates that `RouteSegment` has a total equality relation.
@@ -213,10 +229,13 @@ impl Ord for RouteSegment {
             (Self::Regex(left_name, left_regex), Self::Regex(right_name, right_regex)) => left_name
                 .cmp(right_name)
                 .then_with(|| left_regex.as_str().cmp(right_regex.as_str())),
+            (Self::CatchAll(left_name), Self::CatchAll(right_name)) => left_name.cmp(right_name),
             (Self::Static(_), _) => Ordering::Less,
             (_, Self::Static(_)) => Ordering::Greater,
             (Self::Dynamic(_), _) => Ordering::Less,
             (_, Self::Dynamic(_)) => Ordering::Greater,
+            (Self::Regex(_, _), _) => Ordering::Less,
+            (_, Self::Regex(_, _)) => Ordering::Greater,
         }
     }
 }
@@ -242,6 +261,7 @@ impl PartialEq for RouteSegment {
             (Self::Regex(left_name, left_regex), Self::Regex(right_name, right_regex)) => {
                 left_name == right_name && left_regex.as_str() == right_regex.as_str()
             }
+            (Self::CatchAll(left_name), Self::CatchAll(right_name)) => left_name == right_name,
             _ => false,
         }
     }
@@ -272,6 +292,10 @@ impl Hash for RouteSegment {
                 name.hash(state);
                 regex.as_str().hash(state);
             }
+            Self::CatchAll(name) => {
+                3u8.hash(state);
+                name.hash(state);
+            }
         }
     }
 }
@@ -316,9 +340,26 @@ impl RoutePattern {
         let estimated_segments: usize = route.matches(DEFAULT_HTTP_PATH).count() + 1;
         let mut segments: RouteSegmentList = Vec::with_capacity(estimated_segments);
         for segment in route.split(DEFAULT_HTTP_PATH) {
+            if let Some(RouteSegment::CatchAll(_)) = segments.last() {
+                return Err(RouteError::InvalidCatchAllPattern(format!(
+                    "Catch-all segment must be the last segment of '{route}'"
+                )));
+            }
             if segment.starts_with(LEFT_BRACKET) && segment.ends_with(RIGHT_BRACKET) {
                 let content: &str = &segment[1..segment.len() - 1];
-                if let Some((name, pattern)) = content.split_once(COLON) {
+                if let Some(name) = content.strip_prefix(CATCH_ALL_PREFIX) {
+                    if name.is_empty() {
+                        return Err(RouteError::InvalidCatchAllPattern(format!(
+                            "Catch-all segment must be named in '{route}'"
+                        )));
+                    }
+                    if name.contains(COLON) {
+                        return Err(RouteError::InvalidCatchAllPattern(format!(
+                            "Catch-all segment cannot have a regex in '{route}'"
+                        )));
+                    }
+                    segments.push(RouteSegment::CatchAll(name.to_owned()));
+                } else if let Some((name, pattern)) = content.split_once(COLON) {
                     match Regex::new(pattern) {
                         Ok(regex) => {
                             segments.push(RouteSegment::Regex(name.to_owned(), regex));
@@ -340,319 +381,1106 @@ impl RoutePattern {
         Ok(segments)
     }
 
-    /// Fast-path matcher for purely static route patterns.
+    /// Checks if the route pattern is static.
+    ///
+    /// # Returns
     ///
-    /// Walks the request path bytes once, comparing each segment against the
-    /// expected static literal in the pattern. Performs **no Vec allocation**
-    /// and returns an empty `RouteParams` on success.
+    /// - `bool` - true if the pattern is static, false otherwise.
+    #[inline(always)]
+    pub(crate) fn is_static(&self) -> bool {
+        self.get_0()
+            .iter()
+            .all(|segment: &RouteSegment| matches!(segment, RouteSegment::Static(_)))
+    }
+
+    /// Formats the route pattern back into its route string.
     ///
-    /// The caller must guarantee `self.is_purely_static()` is `true` and that
-    /// `path` is non-empty after the leading `/` has been trimmed.
+    /// # Returns
+    ///
+    /// - `String` - The route string, which parses back into an equal pattern.
+    pub(crate) fn to_route_string(&self) -> String {
+        let mut route: String = String::new();
+        for segment in self.get_0() {
+            route.push_str(DEFAULT_HTTP_PATH);
+            match segment {
+                RouteSegment::Static(value) => route.push_str(value),
+                RouteSegment::Dynamic(name) => {
+                    route.push_str(LEFT_BRACKET);
+                    route.push_str(name);
+                    route.push_str(RIGHT_BRACKET);
+                }
+                RouteSegment::Regex(name, regex) => {
+                    route.push_str(LEFT_BRACKET);
+                    route.push_str(name);
+                    route.push_str(COLON);
+                    route.push_str(regex.as_str());
+                    route.push_str(RIGHT_BRACKET);
+                }
+                RouteSegment::CatchAll(name) => {
+                    route.push_str(LEFT_BRACKET);
+                    route.push_str(CATCH_ALL_PREFIX);
+                    route.push_str(name);
+                    route.push_str(RIGHT_BRACKET);
+                }
+            }
+        }
+        if route.is_empty() {
+            route.push_str(DEFAULT_HTTP_PATH);
+        }
+        route
+    }
+}
+
+/// Manages a collection of route, enabling efficient lookup and dispatch.
+///
+/// This struct stores route categorized by type (static, dynamic, regex)
+/// to quickly find the appropriate hook for incoming requests.
+impl RouteMatcher {
+    /// Creates a new, empty RouteMatcher.
+    ///
+    /// # Returns
+    ///
+    /// - `RouteMatcher` - A new RouteMatcher instance with empty route stores.
+    #[inline(always)]
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a new route and its hook to the matcher.
+    ///
+    /// The hook accepts any HTTP method that has no method-specific hook
+    /// registered on the same pattern.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The route pattern string.
+    /// - `ServerHookHandler` - The boxed route hook.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(), RouteError>` - Ok on success, or RouteError if pattern is duplicate.
+    pub(crate) fn add(&mut self, pattern: &str, hook: ServerHookHandler) -> Result<(), RouteError> {
+        self.insert(pattern, None, hook)
+    }
+
+    /// Adds a new route bound to a specific HTTP method.
     ///
     /// # Arguments
     ///
-    /// - `&str` - The request path (already trimmed of the leading slash).
+    /// - `Method` - The HTTP method the hook responds to.
+    /// - `&str` - The route pattern string.
+    /// - `ServerHookHandler` - The boxed route hook.
     ///
     /// # Returns
     ///
-    /// - `Option<RouteParams>` - `Some` with an empty params map on match, `None` otherwise.
-    fn try_match_static_path(&self, path: &str) -> Option<RouteParams> {
-        let route_segments_len: usize = self.get_0().len();
-        let path_bytes: &[u8] = path.as_bytes();
-        let path_separator_byte: u8 = DEFAULT_HTTP_PATH_BYTES[0];
-        let mut segment_start: usize = 0;
-        let mut matched_segments: usize = 0;
-        let mut saw_content: bool = false;
-        for (byte_index, &current_byte) in path_bytes.iter().enumerate() {
-            if current_byte == path_separator_byte {
-                saw_content = true;
-                let expected: &str = match self.get_0().get(matched_segments) {
-                    Some(RouteSegment::Static(s)) => s.as_str(),
-                    Some(_) => return None,
-                    None => return None,
-                };
-                if &path[segment_start..byte_index] != expected {
-                    return None;
-                }
-                matched_segments += 1;
-                segment_start = byte_index + 1;
+    /// - `Result<(), RouteError>` - Ok on success, or RouteError if the pattern is already
+    ///   registered for this method.
+    pub(crate) fn add_method(
+        &mut self,
+        method: Method,
+        pattern: &str,
+        hook: ServerHookHandler,
+    ) -> Result<(), RouteError> {
+        self.insert(pattern, Some(method), hook)
+    }
+
+    /// Stores a route hook in the static route map or the route tree.
+    ///
+    /// Patterns made only of static segments go to the static route map, every other
+    /// pattern to the node of the route tree it ends at.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The route pattern string.
+    /// - `Option<Method>` - The HTTP method, or `None` to accept any method.
+    /// - `ServerHookHandler` - The boxed route hook.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(), RouteError>` - Ok on success, or RouteError if pattern is duplicate.
+    pub(crate) fn insert(
+        &mut self,
+        pattern: &str,
+        method: Option<Method>,
+        hook: ServerHookHandler,
+    ) -> Result<(), RouteError> {
+        let route_pattern: RoutePattern = RoutePattern::new(pattern)?;
+        if route_pattern.is_static() {
+            let route_handler: &mut RouteHandler = self
+                .get_mut_static_route()
+                .entry(pattern.to_string())
+                .or_default();
+            if !route_handler.insert(method, hook) {
+                return Err(RouteError::DuplicatePattern(pattern.to_owned()));
             }
+            return Ok(());
         }
-        if segment_start < path.len() {
-            saw_content = true;
-            let expected: &str = match self.get_0().get(matched_segments) {
-                Some(RouteSegment::Static(s)) => s.as_str(),
-                Some(_) => return None,
-                None => return None,
-            };
-            if &path[segment_start..] != expected {
-                return None;
-            }
-            matched_segments += 1;
+        let route_handler: &mut RouteHandler = self
+            .get_mut_route_tree()
+            .insert(route_pattern.get_0())?
+            .handler
+            .get_or_insert_default();
+        if !route_handler.insert(method, hook) {
+            return Err(RouteError::DuplicatePattern(pattern.to_owned()));
         }
-        let all_matched: bool = matched_segments == route_segments_len;
-        let empty_single_match: bool = !saw_content && route_segments_len == 1 && path.is_empty();
-        if all_matched || empty_single_match {
-            Some(hash_map_xx_hash3_64())
-        } else {
-            None
+        Ok(())
+    }
+
+    /// Lists every registered route with its method and hook.
+    ///
+    /// # Returns
+    ///
+    /// - `RouteGroupList` - The routes of the matcher, with their route strings.
+    pub fn get_route_list(&self) -> RouteGroupList {
+        let mut route_list: RouteGroupList = Vec::new();
+        for (path, route_handler) in self.get_static_route() {
+            route_handler.push_route_list(path, &mut route_list);
         }
+        self.get_route_tree()
+            .collect_route_list(&mut Vec::new(), &mut route_list);
+        route_list
+    }
+
+    /// Lists the dynamic routes grouped by segment count.
+    ///
+    /// Dynamic routes are now stored in the route tree, so the map is built on every call.
+    ///
+    /// # Returns
+    ///
+    /// - `ServerHookPatternRoute` - The routes with dynamic segments but no regex
+    ///   or catch-all segment.
+    #[deprecated(note = "pattern routes are stored in the route tree; use `get_route_list`")]
+    pub fn get_dynamic_route(&self) -> ServerHookPatternRoute {
+        self.get_pattern_route(|segments: &[RouteSegment]| {
+            segments.iter().all(|segment: &RouteSegment| {
+                matches!(segment, RouteSegment::Static(_) | RouteSegment::Dynamic(_))
+            })
+        })
     }
 
-    /// Matches this route pattern against a request path.
+    /// Lists the regex routes grouped by segment count.
     ///
-    /// If the pattern matches, extracts any dynamic or regex parameters.
+    /// Regex routes are now stored in the route tree, so the map is built on every call.
+    ///
+    /// # Returns
+    ///
+    /// - `ServerHookPatternRoute` - The routes with at least one regex segment and no
+    ///   catch-all segment.
+    #[deprecated(note = "pattern routes are stored in the route tree; use `get_route_list`")]
+    pub fn get_regex_route(&self) -> ServerHookPatternRoute {
+        self.get_pattern_route(|segments: &[RouteSegment]| {
+            segments
+                .iter()
+                .any(|segment: &RouteSegment| matches!(segment, RouteSegment::Regex(..)))
+                && !segments
+                    .iter()
+                    .any(|segment: &RouteSegment| matches!(segment, RouteSegment::CatchAll(_)))
+        })
+    }
+
+    /// Groups the routes of the route tree accepted by a filter by segment count.
     ///
     /// # Arguments
     ///
-    /// - `&str` - The request path to match against.
+    /// - `Fn(&[RouteSegment]) -> bool` - Whether a route pattern is included.
     ///
     /// # Returns
     ///
-    /// - `Option<RouteParams>` - Some with parameters if matched, None otherwise.
-    pub(crate) fn try_match_path(&self, path: &str) -> Option<RouteParams> {
-        let path: &str = path.trim_start_matches(DEFAULT_HTTP_PATH);
-        let route_segments_len: usize = self.get_0().len();
-        let is_tail_regex: bool = matches!(self.get_0().last(), Some(RouteSegment::Regex(_, _)));
-        if path.is_empty() {
-            if route_segments_len == 0 {
-                return Some(hash_map_xx_hash3_64());
-            }
-            return None;
-        }
-        if self.is_static() {
-            return self.try_match_static_path(path);
-        }
-        let mut path_segments: PathComponentList = Vec::with_capacity(route_segments_len);
-        let path_bytes: &[u8] = path.as_bytes();
-        let path_separator_byte: u8 = DEFAULT_HTTP_PATH_BYTES[0];
-        let mut segment_start: usize = 0;
-        for (byte_index, &current_byte) in path_bytes.iter().enumerate() {
-            if current_byte == path_separator_byte {
-                if segment_start < byte_index {
-                    path_segments.push(&path[segment_start..byte_index]);
+    /// - `ServerHookPatternRoute` - The included routes grouped by segment count.
+    fn get_pattern_route<F>(&self, filter: F) -> ServerHookPatternRoute
+    where
+        F: Fn(&[RouteSegment]) -> bool,
+    {
+        let mut pattern_route: ServerHookPatternRoute = ServerHookPatternRoute::default();
+        self.get_route_tree().visit_route(
+            &mut Vec::new(),
+            &mut |segments: &RouteSegmentList, route_handler: &RouteHandler| {
+                if filter(segments) {
+                    pattern_route
+                        .entry(segments.len())
+                        .or_default()
+                        .push((RoutePattern(segments.clone()), route_handler.clone()));
+                }
+            },
+        );
+        pattern_route
+    }
+
+    /// Compiles the regex routes ahead of the first lookup.
+    ///
+    /// Regex routes sharing a position in the route tree are matched through a combined
+    /// `RegexSet`, which is otherwise compiled by the first request reaching it. When the
+    /// combined set exceeds the regex size limit, their regexes are compiled and tried one
+    /// by one instead. The server calls this before accepting connections.
+    pub fn prepare(&self) {
+        self.get_route_tree().prepare();
+    }
+
+    /// Resolves a route hook by reference (no Arc::clone) for hot-path use.
+    ///
+    /// Only hooks accepting the method of the request stored in the context are
+    /// considered. Returns a reference to the matched `ServerHookHandler` if any.
+    /// The caller must clone if it needs to retain the hook beyond the await point.
+    /// Returns `None` if no route matched.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut Context` - The request context (for reading the method and storing route params).
+    /// - `&str` - The request path to resolve.
+    pub fn try_resolve_route<'a>(
+        &'a self,
+        ctx: &mut Context,
+        path: &str,
+    ) -> Option<&'a ServerHookHandler> {
+        let method: &Method = ctx.get_request().get_method();
+        if let Some(hook) = self
+            .get_static_route()
+            .get(path)
+            .and_then(|route_handler: &RouteHandler| route_handler.try_get_hook(method))
+        {
+            return Some(hook);
+        }
+        let mut resolved: Option<(&'a ServerHookHandler, RouteParams)> = None;
+        self.get_route_tree().find(
+            path,
+            &mut |route_handler: &'a RouteHandler, params: &[(&'a str, &str)]| {
+                let Some(hook) = route_handler.try_get_hook(method) else {
+                    return false;
+                };
+                let mut route_params: RouteParams = hash_map_xx_hash3_64();
+                for (name, value) in params {
+                    route_params.insert((*name).to_owned(), (*value).to_owned());
+                }
+                resolved = Some((hook, route_params));
+                true
+            },
+        );
+        let (hook, route_params) = resolved?;
+        ctx.set_route_params(route_params);
+        Some(hook)
+    }
+
+    /// Collects the HTTP methods registered on every route matching a path.
+    ///
+    /// This is used to build the `Allow` header of a `405 Method Not Allowed`
+    /// response when a path matches but none of its hooks accepts the request method.
+    /// Methods are returned in registration order without duplicates.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The request path to inspect.
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<Method>` - The registered methods, empty if no method-specific route matches.
+    pub fn get_allowed_methods(&self, path: &str) -> Vec<Method> {
+        let mut allowed_methods: Vec<Method> = Vec::new();
+        let mut collect = |route_handler: &RouteHandler| {
+            for (method, _) in route_handler.get_method_list() {
+                if !allowed_methods.contains(method) {
+                    allowed_methods.push(method.clone());
                 }
-                segment_start = byte_index + 1;
             }
+        };
+        if let Some(route_handler) = self.get_static_route().get(path) {
+            collect(route_handler);
         }
-        if segment_start < path.len() {
-            path_segments.push(&path[segment_start..]);
+        self.get_route_tree().find(
+            path,
+            &mut |route_handler: &RouteHandler, _: &[(&str, &str)]| {
+                collect(route_handler);
+                false
+            },
+        );
+        allowed_methods
+    }
+}
+
+/// Implementation of the route tree lookup for `RouteNode`.
+impl RouteNode {
+    /// Anchors a route segment regex so that it only matches whole values.
+    ///
+    /// # Arguments
+    ///
+    /// - `&Regex` - The regex of the route segment.
+    ///
+    /// # Returns
+    ///
+    /// - `String` - The anchored pattern.
+    #[inline(always)]
+    fn anchor_pattern(regex: &Regex) -> String {
+        format!("^(?:{})$", regex.as_str())
+    }
+
+    /// Compiles the anchored regexes of the regex children.
+    ///
+    /// # Returns
+    ///
+    /// - `RegexChildMatcher` - A `RegexSet` of every regex, or the regexes compiled one by
+    ///   one if the combined set exceeds the regex size limit.
+    fn build_regex_matcher(&self) -> RegexChildMatcher {
+        let patterns: Vec<(usize, String)> = self
+            .regex_children
+            .iter()
+            .enumerate()
+            .filter_map(
+                |(index, (segment, _)): (usize, &(RouteSegment, RouteNode))| match segment {
+                    RouteSegment::Regex(_, regex) => Some((index, Self::anchor_pattern(regex))),
+                    _ => None,
+                },
+            )
+            .collect();
+        if let Ok(regex_set) = RegexSet::new(
+            patterns
+                .iter()
+                .map(|(_, pattern): &(usize, String)| pattern),
+        ) {
+            return RegexChildMatcher::Set(regex_set);
+        }
+        RegexChildMatcher::List(
+            patterns
+                .iter()
+                .filter_map(|(index, pattern): &(usize, String)| {
+                    Regex::new(pattern).ok().map(|regex: Regex| (*index, regex))
+                })
+                .collect(),
+        )
+    }
+
+    /// Returns the compiled regexes of the regex children, compiling them if needed.
+    ///
+    /// # Returns
+    ///
+    /// - `&RegexChildMatcher` - The compiled regexes.
+    #[inline(always)]
+    fn get_regex_matcher(&self) -> &RegexChildMatcher {
+        self.regex_matcher
+            .get_or_init(|| self.build_regex_matcher())
+    }
+
+    /// Visits the indices of the regex children whose regex matches a whole value, in order.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The value to match.
+    /// - `FnMut(usize) -> bool` - The visitor of matching indices, returning `true` to stop.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - `true` if the visitor stopped the walk.
+    fn find_regex_children<F>(&self, value: &str, mut visitor: F) -> bool
+    where
+        F: FnMut(usize) -> bool,
+    {
+        match self.get_regex_matcher() {
+            RegexChildMatcher::Set(regex_set) => regex_set.matches(value).iter().any(visitor),
+            RegexChildMatcher::List(regex_list) => regex_list
+                .iter()
+                .any(|(index, regex): &(usize, Regex)| regex.is_match(value) && visitor(*index)),
+        }
+    }
+
+    /// Compiles the regexes of this node and of every node below it.
+    pub(crate) fn prepare(&self) {
+        if !self.regex_children.is_empty() {
+            self.get_regex_matcher();
+        }
+        for child in self.static_children.values() {
+            child.prepare();
+        }
+        for (_, child) in &self.dynamic_children {
+            child.prepare();
+        }
+        for (_, child) in &self.regex_children {
+            child.prepare();
         }
-        let path_segments_len: usize = path_segments.len();
-        if (!is_tail_regex && path_segments_len != route_segments_len)
-            || (is_tail_regex && path_segments_len < route_segments_len - 1)
+    }
+
+    /// Lists the routes ending at this node or below it.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut RouteSegmentList` - The segments leading to this node.
+    /// - `&mut RouteGroupList` - The list receiving the routes.
+    fn collect_route_list(&self, segments: &mut RouteSegmentList, route_list: &mut RouteGroupList) {
+        self.visit_route(
+            segments,
+            &mut |segments: &RouteSegmentList, route_handler: &RouteHandler| {
+                let path: String = RoutePattern(segments.clone()).to_route_string();
+                route_handler.push_route_list(&path, route_list);
+            },
+        );
+    }
+
+    /// Calls a visitor with every route ending at this node or below it.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut RouteSegmentList` - The segments leading to this node.
+    /// - `&mut FnMut(&RouteSegmentList, &RouteHandler)` - The visitor receiving the
+    ///   segments and hooks of each route.
+    fn visit_route<F>(&self, segments: &mut RouteSegmentList, visitor: &mut F)
+    where
+        F: FnMut(&RouteSegmentList, &RouteHandler),
+    {
+        if let Some(route_handler) = &self.handler {
+            visitor(segments, route_handler);
+        }
+        let static_children =
+            self.static_children
+                .iter()
+                .map(|(value, child): (&String, &RouteNode)| {
+                    (RouteSegment::Static(value.clone()), child)
+                });
+        let dynamic_children =
+            self.dynamic_children
+                .iter()
+                .map(|(name, child): &(String, RouteNode)| {
+                    (RouteSegment::Dynamic(name.clone()), child)
+                });
+        let regex_children = self
+            .regex_children
+            .iter()
+            .map(|(segment, child): &(RouteSegment, RouteNode)| (segment.clone(), child));
+        let catch_all_children =
+            self.catch_all_children
+                .iter()
+                .map(|(name, child): &(String, RouteNode)| {
+                    (RouteSegment::CatchAll(name.clone()), child)
+                });
+        for (segment, child) in static_children
+            .chain(dynamic_children)
+            .chain(regex_children)
+            .chain(catch_all_children)
         {
-            return None;
+            segments.push(segment);
+            child.visit_route(segments, visitor);
+            segments.pop();
         }
-        let mut params: RouteParams = hash_map_xx_hash3_64();
-        for (idx, segment) in self.get_0().iter().enumerate() {
-            match segment {
-                RouteSegment::Static(expected_path) => {
-                    if path_segments.get(idx).copied() != Some(expected_path.as_str()) {
-                        return None;
+    }
+
+    /// Returns the node reached by a route pattern, creating missing nodes.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[RouteSegment]` - The segments of the route pattern.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<&mut RouteNode, RouteError>` - The node the route pattern ends at, or
+    ///   RouteError if a regex cannot be compiled into the node's `RegexSet`.
+    pub(crate) fn insert(
+        &mut self,
+        segments: &[RouteSegment],
+    ) -> Result<&mut RouteNode, RouteError> {
+        let Some((segment, rest)) = segments.split_first() else {
+            return Ok(self);
+        };
+        let child: &mut RouteNode = match segment {
+            RouteSegment::Static(value) => self.static_children.entry(value.clone()).or_default(),
+            RouteSegment::Dynamic(name) => {
+                let position: usize = match self
+                    .dynamic_children
+                    .binary_search_by(|(child_name, _): &(String, RouteNode)| child_name.cmp(name))
+                {
+                    Ok(position) => position,
+                    Err(position) => {
+                        self.dynamic_children
+                            .insert(position, (name.clone(), RouteNode::default()));
+                        position
                     }
-                }
-                RouteSegment::Dynamic(param_name) => {
-                    params.insert(param_name.clone(), path_segments.get(idx)?.to_string());
-                }
-                RouteSegment::Regex(param_name, regex) => {
-                    let segment_value: String = if idx == route_segments_len - 1 {
-                        path_segments[idx..].join(DEFAULT_HTTP_PATH)
-                    } else {
-                        match path_segments.get(idx) {
-                            Some(val) => val.to_string(),
-                            None => return None,
-                        }
-                    };
-                    if let Some(mat) = regex.find(&segment_value) {
-                        if mat.start() != 0 || mat.end() != segment_value.len() {
-                            return None;
+                };
+                &mut self.dynamic_children[position].1
+            }
+            RouteSegment::Regex(_, _) => {
+                let position: usize = match self.regex_children.binary_search_by(
+                    |(child_segment, _): &(RouteSegment, RouteNode)| child_segment.cmp(segment),
+                ) {
+                    Ok(position) => position,
+                    Err(position) => {
+                        if let RouteSegment::Regex(_, regex) = segment {
+                            Regex::new(&Self::anchor_pattern(regex)).map_err(|error| {
+                                RouteError::InvalidRegexPattern(error.to_string())
+                            })?;
                         }
-                    } else {
-                        return None;
+                        self.regex_children
+                            .insert(position, (segment.clone(), RouteNode::default()));
+                        self.regex_matcher = OnceLock::new();
+                        position
                     }
-                    params.insert(param_name.clone(), segment_value);
-                    if idx == route_segments_len - 1 {
-                        return Some(params);
+                };
+                &mut self.regex_children[position].1
+            }
+            RouteSegment::CatchAll(name) => {
+                let position: usize = match self
+                    .catch_all_children
+                    .binary_search_by(|(child_name, _): &(String, RouteNode)| child_name.cmp(name))
+                {
+                    Ok(position) => position,
+                    Err(position) => {
+                        self.catch_all_children
+                            .insert(position, (name.clone(), RouteNode::default()));
+                        position
                     }
+                };
+                &mut self.catch_all_children[position].1
+            }
+        };
+        child.insert(rest)
+    }
+
+    /// Walks the tree for every route matching a path, in precedence order.
+    ///
+    /// At each segment, static children are tried before dynamic ones, then regex ones.
+    /// A regex ending a route may then match the whole remainder of the path, and a
+    /// catch-all segment is tried last. The visitor receives the hooks and parameters of
+    /// each matching route, and stops the walk by returning `true`.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The request path.
+    /// - `FnMut(&RouteHandler, &[(&str, &str)]) -> bool` - The visitor of matching routes.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - `true` if the visitor stopped the walk.
+    pub(crate) fn find<'a, 'p, F>(&'a self, path: &'p str, visitor: &mut F) -> bool
+    where
+        F: FnMut(&'a RouteHandler, &[(&'a str, &'p str)]) -> bool,
+    {
+        let path: &str = path.trim_start_matches(DEFAULT_HTTP_PATH);
+        let mut components: PathComponentList = Vec::new();
+        if !path.is_empty() {
+            let mut offset: usize = 0;
+            for component in path.split(DEFAULT_HTTP_PATH) {
+                components.push((offset, component));
+                offset += component.len() + DEFAULT_HTTP_PATH.len();
+            }
+        }
+        let mut params: Vec<(&'a str, &'p str)> = Vec::new();
+        self.find_from(path, &components, &mut params, visitor)
+    }
+
+    /// Walks the subtree of this node for the remaining path components.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The request path without its leading separator.
+    /// - `&[(usize, &str)]` - The remaining path components with their offsets.
+    /// - `&mut Vec<(&str, &str)>` - The parameters captured so far.
+    /// - `FnMut(&RouteHandler, &[(&str, &str)]) -> bool` - The visitor of matching routes.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - `true` if the visitor stopped the walk.
+    fn find_from<'a, 'p, F>(
+        &'a self,
+        path: &'p str,
+        components: &[(usize, &'p str)],
+        params: &mut Vec<(&'a str, &'p str)>,
+        visitor: &mut F,
+    ) -> bool
+    where
+        F: FnMut(&'a RouteHandler, &[(&'a str, &'p str)]) -> bool,
+    {
+        let Some((&(offset, component), rest)) = components.split_first() else {
+            if let Some(handler) = &self.handler
+                && visitor(handler, params)
+            {
+                return true;
+            }
+            return self.find_catch_all("", params, visitor);
+        };
+        if let Some(child) = self.static_children.get(component)
+            && child.find_from(path, rest, params, visitor)
+        {
+            return true;
+        }
+        if !component.is_empty() {
+            for (name, child) in &self.dynamic_children {
+                params.push((name, component));
+                let stopped: bool = child.find_from(path, rest, params, visitor);
+                params.pop();
+                if stopped {
+                    return true;
                 }
             }
         }
-        Some(params)
+        let remainder: &'p str = path[offset..].trim_end_matches(DEFAULT_HTTP_PATH);
+        if !self.regex_children.is_empty() {
+            if !component.is_empty()
+                && self.find_regex_children(component, |index: usize| {
+                    let (segment, child): &'a (RouteSegment, RouteNode) =
+                        &self.regex_children[index];
+                    let RouteSegment::Regex(name, _) = segment else {
+                        return false;
+                    };
+                    params.push((name, component));
+                    let stopped: bool = child.find_from(path, rest, params, visitor);
+                    params.pop();
+                    stopped
+                })
+            {
+                return true;
+            }
+            if (!rest.is_empty() || component.is_empty())
+                && self.find_regex_children(remainder, |index: usize| {
+                    let (segment, child): &'a (RouteSegment, RouteNode) =
+                        &self.regex_children[index];
+                    let (RouteSegment::Regex(name, _), Some(handler)) = (segment, &child.handler)
+                    else {
+                        return false;
+                    };
+                    params.push((name, remainder));
+                    let stopped: bool = visitor(handler, params);
+                    params.pop();
+                    stopped
+                })
+            {
+                return true;
+            }
+        }
+        self.find_catch_all(remainder, params, visitor)
     }
 
-    /// Checks if the route pattern is static.
+    /// Visits the catch-all routes of this node with the rest of the path.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The rest of the path, possibly empty.
+    /// - `&mut Vec<(&str, &str)>` - The parameters captured so far.
+    /// - `FnMut(&RouteHandler, &[(&str, &str)]) -> bool` - The visitor of matching routes.
     ///
     /// # Returns
     ///
-    /// - `bool` - true if the pattern is static, false otherwise.
+    /// - `bool` - `true` if the visitor stopped the walk.
+    fn find_catch_all<'a, 'p, F>(
+        &'a self,
+        remainder: &'p str,
+        params: &mut Vec<(&'a str, &'p str)>,
+        visitor: &mut F,
+    ) -> bool
+    where
+        F: FnMut(&'a RouteHandler, &[(&'a str, &'p str)]) -> bool,
+    {
+        for (name, child) in &self.catch_all_children {
+            if let Some(handler) = &child.handler {
+                params.push((name, remainder));
+                let stopped: bool = visitor(handler, params);
+                params.pop();
+                if stopped {
+                    return true;
+                }
+            }
+        }
+        false
+    }
+}
+
+/// Manages the hooks registered for a single route pattern.
+///
+/// This struct resolves which hook handles a request based on its HTTP method.
+impl RouteHandler {
+    /// Registers a hook for a method, or for any method when `None` is given.
+    ///
+    /// # Arguments
+    ///
+    /// - `Option<Method>` - The HTTP method, or `None` to accept any method.
+    /// - `ServerHookHandler` - The hook to register.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - `true` if the hook was registered, `false` if one already exists for the method.
+    pub(crate) fn insert(&mut self, method: Option<Method>, hook: ServerHookHandler) -> bool {
+        match method {
+            None => {
+                if self.try_get_any_method().is_some() {
+                    return false;
+                }
+                self.set_any_method(Some(hook));
+            }
+            Some(method) => {
+                if self.has_method(&method) {
+                    return false;
+                }
+                self.get_mut_method_list().push((method, hook));
+            }
+        }
+        true
+    }
+
+    /// Checks whether a hook is bound to the given method.
+    ///
+    /// # Arguments
+    ///
+    /// - `&Method` - The HTTP method to check.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - `true` if a method-specific hook exists for the method.
     #[inline(always)]
-    pub(crate) fn is_static(&self) -> bool {
-        self.get_0()
+    pub fn has_method(&self, method: &Method) -> bool {
+        self.get_method_list()
             .iter()
-            .all(|segment: &RouteSegment| matches!(segment, RouteSegment::Static(_)))
+            .any(|(bound_method, _): &(Method, ServerHookHandler)| bound_method == method)
     }
 
-    /// Checks if the route pattern is dynamic.
+    /// Resolves the hook responsible for the given method.
+    ///
+    /// A method-specific hook is preferred over the any-method hook.
+    ///
+    /// # Arguments
+    ///
+    /// - `&Method` - The HTTP method of the request.
     ///
     /// # Returns
     ///
-    /// - `bool` - true if the pattern is dynamic, false otherwise.
+    /// - `Option<&ServerHookHandler>` - The matching hook, if any.
     #[inline(always)]
-    pub(crate) fn is_dynamic(&self) -> bool {
-        self.get_0()
+    pub fn try_get_hook(&self, method: &Method) -> Option<&ServerHookHandler> {
+        self.get_method_list()
             .iter()
-            .any(|segment: &RouteSegment| matches!(segment, RouteSegment::Dynamic(_)))
-            && self
-                .get_0()
-                .iter()
-                .all(|segment: &RouteSegment| !matches!(segment, RouteSegment::Regex(_, _)))
+            .find(|(bound_method, _): &&(Method, ServerHookHandler)| bound_method == method)
+            .map(|(_, hook): &(Method, ServerHookHandler)| hook)
+            .or(self.try_get_any_method().as_ref())
+    }
+
+    /// Appends one route list entry per hook, the any-method hook first.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The route string of the hooks.
+    /// - `&mut RouteGroupList` - The list receiving the entries.
+    pub(crate) fn push_route_list(&self, path: &str, route_list: &mut RouteGroupList) {
+        if let Some(hook) = self.try_get_any_method() {
+            route_list.push((None, path.to_owned(), hook.clone()));
+        }
+        for (method, hook) in self.get_method_list() {
+            route_list.push((Some(method.clone()), path.to_owned(), hook.clone()));
+        }
+    }
+}
+
+/// Implementation of methods for `RouteGroup`.
+impl RouteGroup {
+    /// Creates an empty route group with the given path prefix.
+    ///
+    /// # Arguments
+    ///
+    /// - `AsRef<str>` - The path prefix shared by the routes of the group.
+    ///
+    /// # Returns
+    ///
+    /// - `RouteGroup` - A new, empty route group.
+    #[inline(always)]
+    pub fn new(prefix: impl AsRef<str>) -> Self {
+        Self {
+            prefix: prefix.as_ref().to_owned(),
+            request_middleware: Vec::new(),
+            response_middleware: Vec::new(),
+            route_list: Vec::new(),
+        }
+    }
+
+    /// Joins a path prefix and a route path with a single separator.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The path prefix.
+    /// - `&str` - The route path.
+    ///
+    /// # Returns
+    ///
+    /// - `String` - The joined path.
+    pub fn join_path(prefix: &str, path: &str) -> String {
+        let prefix: &str = prefix.trim_end_matches(DEFAULT_HTTP_PATH);
+        let path: &str = path.trim_start_matches(DEFAULT_HTTP_PATH);
+        if path.is_empty() {
+            if prefix.is_empty() {
+                return DEFAULT_HTTP_PATH.to_owned();
+            }
+            return prefix.to_owned();
+        }
+        format!("{prefix}{DEFAULT_HTTP_PATH}{path}")
     }
 
-    /// Gets the number of segments in this route pattern.
+    /// Creates a route group from routes and middleware collected elsewhere.
+    ///
+    /// # Arguments
+    ///
+    /// - `AsRef<str>` - The path prefix shared by the routes of the group.
+    /// - `RouteGroupList` - The routes of the group, relative to its prefix.
+    /// - `ServerHookList` - The request middleware executed before the routes.
+    /// - `ServerHookList` - The response middleware executed after the routes.
     ///
     /// # Returns
     ///
-    /// - `usize` - The number of segments.
+    /// - `RouteGroup` - A new route group.
     #[inline(always)]
-    pub(crate) fn segment_count(&self) -> usize {
-        self.get_0().len()
+    pub(crate) fn from_parts(
+        prefix: impl AsRef<str>,
+        route_list: RouteGroupList,
+        request_middleware: ServerHookList,
+        response_middleware: ServerHookList,
+    ) -> Self {
+        Self {
+            prefix: prefix.as_ref().to_owned(),
+            request_middleware,
+            response_middleware,
+            route_list,
+        }
     }
 
-    /// Checks if the last segment is a regex pattern.
+    /// Registers a route hook on the group.
+    ///
+    /// # Arguments
+    ///
+    /// - `AsRef<str>` - The route path pattern, relative to the group prefix.
     ///
     /// # Returns
     ///
-    /// - `bool` - true if the last segment is a regex, false otherwise.
+    /// - `&mut Self` - Reference to self for method chaining.
     #[inline(always)]
-    pub(crate) fn has_tail_regex(&self) -> bool {
-        matches!(self.get_0().last(), Some(RouteSegment::Regex(_, _)))
+    pub fn route<S>(&mut self, path: impl AsRef<str>) -> &mut Self
+    where
+        S: ServerHook,
+    {
+        self.route_list
+            .push((None, path.as_ref().to_owned(), Hook::factory::<S>()));
+        self
     }
-}
 
-/// Manages a collection of route, enabling efficient lookup and dispatch.
-///
-/// This struct stores route categorized by type (static, dynamic, regex)
-/// to quickly find the appropriate hook for incoming requests.
-impl RouteMatcher {
-    /// Creates a new, empty RouteMatcher.
+    /// Registers a route hook for a specific HTTP method on the group.
+    ///
+    /// # Arguments
+    ///
+    /// - `Method` - The HTTP method the route responds to.
+    /// - `AsRef<str>` - The route path pattern, relative to the group prefix.
     ///
     /// # Returns
     ///
-    /// - `RouteMatcher` - A new RouteMatcher instance with empty route stores.
+    /// - `&mut Self` - Reference to self for method chaining.
     #[inline(always)]
-    pub(crate) fn new() -> Self {
-        Self::default()
+    pub fn route_method<S>(&mut self, method: Method, path: impl AsRef<str>) -> &mut Self
+    where
+        S: ServerHook,
+    {
+        self.route_list
+            .push((Some(method), path.as_ref().to_owned(), Hook::factory::<S>()));
+        self
     }
 
-    /// Counts the number of segments in a path.
+    /// Registers request middleware scoped to the group.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Self` - Reference to self for method chaining.
+    #[inline(always)]
+    pub fn request_middleware<S>(&mut self) -> &mut Self
+    where
+        S: ServerHook,
+    {
+        self.request_middleware_with_order::<S>(0)
+    }
+
+    /// Registers request middleware scoped to the group with an explicit priority.
+    ///
+    /// Handlers with higher priority execute first. Handlers with equal
+    /// priority execute in registration order.
     ///
     /// # Arguments
     ///
-    /// - `&str` - The path to count segments in.
+    /// - `isize` - The priority of the handler.
     ///
     /// # Returns
     ///
-    /// - `usize` - The number of segments.
+    /// - `&mut Self` - Reference to self for method chaining.
     #[inline(always)]
-    fn count_path_segments(path: &str) -> usize {
-        let path: &str = path.trim_start_matches(DEFAULT_HTTP_PATH);
-        if path.is_empty() {
-            return 0;
-        }
-        path.matches(DEFAULT_HTTP_PATH).count() + 1
+    pub fn request_middleware_with_order<S>(&mut self, order: isize) -> &mut Self
+    where
+        S: ServerHook,
+    {
+        Hook::insert_by_order(
+            &mut self.request_middleware,
+            Some(order),
+            Hook::factory::<S>(),
+        );
+        self
     }
 
-    /// Adds a new route and its hook to the matcher.
+    /// Registers response middleware scoped to the group.
     ///
-    /// Adds a route hook to the matcher.
+    /// # Returns
     ///
-    /// This method categorizes the route as static, dynamic, or regex based on its pattern
-    /// and stores it in the appropriate collection.
+    /// - `&mut Self` - Reference to self for method chaining.
+    #[inline(always)]
+    pub fn response_middleware<S>(&mut self) -> &mut Self
+    where
+        S: ServerHook,
+    {
+        self.response_middleware_with_order::<S>(0)
+    }
+
+    /// Registers response middleware scoped to the group with an explicit priority.
+    ///
+    /// Handlers with higher priority execute first. Handlers with equal
+    /// priority execute in registration order.
     ///
     /// # Arguments
     ///
-    /// - `&str` - The route pattern string.
-    /// - `ServerHookHandler` - The boxed route hook.
+    /// - `isize` - The priority of the handler.
     ///
     /// # Returns
     ///
-    /// - `Result<(), RouteError>` - Ok on success, or RouteError if pattern is duplicate.
-    pub(crate) fn add(&mut self, pattern: &str, hook: ServerHookHandler) -> Result<(), RouteError> {
-        let route_pattern: RoutePattern = RoutePattern::new(pattern)?;
-        if route_pattern.is_static() {
-            if self.get_static_route().contains_key(pattern) {
-                return Err(RouteError::DuplicatePattern(pattern.to_owned()));
-            }
-            self.get_mut_static_route()
-                .insert(pattern.to_string(), hook);
-            return Ok(());
-        }
-        let target_map: &mut ServerHookPatternRoute = if route_pattern.is_dynamic() {
-            self.get_mut_dynamic_route()
-        } else {
-            self.get_mut_regex_route()
-        };
-        let segment_count: usize = route_pattern.segment_count();
-        let routes_for_count: &mut Vec<(RoutePattern, ServerHookHandler)> =
-            target_map.entry(segment_count).or_default();
-        match routes_for_count.binary_search_by(|entry: &(RoutePattern, ServerHookHandler)| {
-            entry.0.cmp(&route_pattern)
-        }) {
-            Ok(_) => return Err(RouteError::DuplicatePattern(pattern.to_owned())),
-            Err(pos) => routes_for_count.insert(pos, (route_pattern, hook)),
-        }
-        Ok(())
+    /// - `&mut Self` - Reference to self for method chaining.
+    #[inline(always)]
+    pub fn response_middleware_with_order<S>(&mut self, order: isize) -> &mut Self
+    where
+        S: ServerHook,
+    {
+        Hook::insert_by_order(
+            &mut self.response_middleware,
+            Some(order),
+            Hook::factory::<S>(),
+        );
+        self
     }
 
-    /// Resolves a route hook by reference (no Arc::clone) for hot-path use.
+    /// Registers a nested group under the prefix of this group.
     ///
-    /// Returns a reference to the matched `ServerHookHandler` if any. The
-    /// caller must clone if it needs to retain the hook beyond the await point.
-    /// Returns `None` if no route matched.
+    /// # Arguments
+    ///
+    /// - `AsRef<str>` - The path prefix of the nested group, relative to this group.
+    /// - `FnOnce(&mut RouteGroup)` - The function registering the nested group's routes and middleware.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Self` - Reference to self for method chaining.
+    pub fn group<F>(&mut self, prefix: impl AsRef<str>, register: F) -> &mut Self
+    where
+        F: FnOnce(&mut RouteGroup),
+    {
+        let mut group: RouteGroup = RouteGroup::new(prefix);
+        register(&mut group);
+        self.route_list.extend(group.into_route_list());
+        self
+    }
+
+    /// Consumes the group into its routes, with full paths and scoped middleware applied.
+    ///
+    /// # Returns
+    ///
+    /// - `RouteGroupList` - The routes of the group, ready to be added to a `RouteMatcher`.
+    pub(crate) fn into_route_list(self) -> RouteGroupList {
+        let prefix: String = self.prefix;
+        let request_middleware: Arc<ServerHookList> = Arc::new(self.request_middleware);
+        let response_middleware: Arc<ServerHookList> = Arc::new(self.response_middleware);
+        self.route_list
+            .into_iter()
+            .map(|(method, path, hook)| {
+                let hook: ServerHookHandler =
+                    if request_middleware.is_empty() && response_middleware.is_empty() {
+                        hook
+                    } else {
+                        Self::wrap_hook(
+                            request_middleware.clone(),
+                            response_middleware.clone(),
+                            hook,
+                        )
+                    };
+                (method, Self::join_path(&prefix, &path), hook)
+            })
+            .collect()
+    }
+
+    /// Checks whether a request path lies under a path prefix.
+    ///
+    /// The prefix only matches whole segments, so `/api` covers `/api` and `/api/users`
+    /// but not `/apis`.
     ///
     /// # Arguments
     ///
-    /// - `&mut Context` - The request context (for storing route params).
-    /// - `&str` - The request path to resolve.
-    pub fn try_resolve_route<'a>(
-        &'a self,
-        ctx: &mut Context,
-        path: &str,
-    ) -> Option<&'a ServerHookHandler> {
-        if let Some(hook) = self.get_static_route().get(path) {
-            return Some(hook);
-        }
-        let path_segment_count: usize = Self::count_path_segments(path);
-        if let Some(routes) = self.get_dynamic_route().get(&path_segment_count) {
-            for (pattern, hook) in routes {
-                if let Some(params) = pattern.try_match_path(path) {
-                    ctx.set_route_params(params);
-                    return Some(hook);
-                }
-            }
-        }
-        if let Some(routes) = self.get_regex_route().get(&path_segment_count) {
-            for (pattern, hook) in routes {
-                if let Some(params) = pattern.try_match_path(path) {
-                    ctx.set_route_params(params);
-                    return Some(hook);
-                }
-            }
+    /// - `&str` - The path prefix.
+    /// - `&str` - The request path.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - `true` if the path lies under the prefix.
+    pub fn is_under_prefix(prefix: &str, path: &str) -> bool {
+        let prefix: &str = prefix.trim_end_matches(DEFAULT_HTTP_PATH);
+        match path.strip_prefix(prefix) {
+            Some(rest) => rest.is_empty() || rest.starts_with(DEFAULT_HTTP_PATH),
+            None => false,
         }
-        for (&segment_count, routes) in self.get_regex_route() {
-            if segment_count >= path_segment_count {
-                continue;
-            }
-            for (pattern, hook) in routes {
-                if pattern.has_tail_regex()
-                    && let Some(params) = pattern.try_match_path(path)
-                {
-                    ctx.set_route_params(params);
-                    return Some(hook);
+    }
+
+    /// Wraps a hook so that it only runs for request paths under the group prefix.
+    ///
+    /// For other paths the wrapped hook does nothing and returns `Status::Continue`.
+    ///
+    /// # Arguments
+    ///
+    /// - `ServerHookHandler` - The hook to scope.
+    ///
+    /// # Returns
+    ///
+    /// - `ServerHookHandler` - The scoped hook.
+    pub(crate) fn scope_hook(&self, hook: ServerHookHandler) -> ServerHookHandler {
+        let prefix: Arc<str> = Arc::from(self.prefix.as_str());
+        Arc::new(
+            move |stream: &mut Stream, ctx: &mut Context| -> FutureBox<Status> {
+                if !Self::is_under_prefix(&prefix, ctx.get_request().get_path()) {
+                    return Box::pin(async { Status::Continue });
                 }
-            }
-        }
-        None
+                hook(stream, ctx)
+            },
+        )
+    }
+
+    /// Wraps a route hook so that it runs between the given middleware.
+    ///
+    /// Any hook returning `Status::Reject` aborts the remaining ones.
+    ///
+    /// # Arguments
+    ///
+    /// - `Arc<ServerHookList>` - The request middleware executed before the hook.
+    /// - `Arc<ServerHookList>` - The response middleware executed after the hook.
+    /// - `ServerHookHandler` - The route hook.
+    ///
+    /// # Returns
+    ///
+    /// - `ServerHookHandler` - The wrapped route hook.
+    fn wrap_hook(
+        request_middleware: Arc<ServerHookList>,
+        response_middleware: Arc<ServerHookList>,
+        hook: ServerHookHandler,
+    ) -> ServerHookHandler {
+        Arc::new(
+            move |stream: &mut Stream, ctx: &mut Context| -> FutureBox<Status> {
+                let ctx_address: usize = ctx.into();
+                let stream_address: usize = stream.into();
+                let request_middleware: Arc<ServerHookList> = request_middleware.clone();
+                let response_middleware: Arc<ServerHookList> = response_middleware.clone();
+                let hook: ServerHookHandler = hook.clone();
+                Box::pin(async move {
+                    let ctx: &mut Context = ctx_address.into();
+                    let stream: &mut Stream = stream_address.into();
+                    for (_, middleware) in request_middleware.iter() {
+                        if middleware(stream, ctx).await.is_reject() {
+                            return Status::Reject;
+                        }
+                    }
+                    if hook(stream, ctx).await.is_reject() {
+                        return Status::Reject;
+                    }
+                    for (_, middleware) in response_middleware.iter() {
+                        if middleware(stream, ctx).await.is_reject() {
+                            return Status::Reject;
+                        }
+                    }
+                    Status::Continue
+                })
+            },
+        )
     }
 }
@@ -1,8 +1,9 @@
+mod r#const;
 mod r#enum;
 mod r#impl;
 mod r#struct;
 mod r#type;
 
-pub use {r#enum::*, r#struct::*, r#type::*};
+pub use {r#const::*, r#enum::*, r#struct::*, r#type::*};
 
 use super::*;
@@ -12,14 +12,57 @@ pub struct RoutePattern(
     pub(super) RouteSegmentList,
 );
 
+/// The hooks registered for a single route pattern, dispatched by HTTP method.
+///
+/// A route may hold one hook that accepts any method, alongside hooks bound to
+/// specific methods. Method-specific hooks take precedence over the any-method hook.
+#[derive(Clone, CustomDebug, Default, DisplayDebug, Getter, GetterMut, Setter)]
+pub struct RouteHandler {
+    /// The hook invoked for methods without a dedicated hook.
+    #[debug(skip)]
+    pub(super) any_method: Option<ServerHookHandler>,
+    /// The method-specific hooks, kept in registration order.
+    #[debug(skip)]
+    pub(super) method_list: ServerHookMethodList,
+}
+
+/// A node of the route tree, holding the routes that continue past one path segment.
+///
+/// Children are kept per segment kind so that a lookup can try them in precedence
+/// order: static, then dynamic, then regex, then tail regex.
+#[derive(Clone, CustomDebug, DisplayDebug, Getter)]
+pub struct RouteNode {
+    /// The children reached through a literal segment, keyed by the segment.
+    #[debug(skip)]
+    pub(super) static_children: HashMapXxHash3_64<String, RouteNode>,
+    /// The children reached through a `{name}` segment, sorted by parameter name.
+    pub(super) dynamic_children: Vec<(String, RouteNode)>,
+    /// The children reached through a `{name:regex}` segment, sorted by segment.
+    pub(super) regex_children: Vec<(RouteSegment, RouteNode)>,
+    /// The anchored regexes of `regex_children`, compiled into one `RegexSet` when it fits
+    /// the regex size limit, or one by one otherwise.
+    ///
+    /// It is compiled by `RouteMatcher::prepare` or the first lookup, and discarded whenever
+    /// a regex child is added.
+    #[debug(skip)]
+    #[get(skip)]
+    pub(super) regex_matcher: OnceLock<RegexChildMatcher>,
+    /// The children reached through a `{*name}` segment, sorted by parameter name.
+    pub(super) catch_all_children: Vec<(String, RouteNode)>,
+    /// The hooks of the route ending at this node.
+    #[debug(skip)]
+    pub(super) handler: Option<RouteHandler>,
+}
+
 /// The core routing engine responsible for matching request paths to their corresponding handlers.
 ///
-/// The matcher categorizes route into three types for optimized performance:
+/// The matcher stores route in two places:
 /// 1.  `static_route`- For exact path matches, offering the fastest lookups.
-/// 2.  `dynamic_route`- For paths with variable segments.
-/// 3.  `regex_route`- For complex matching based on regular expressions.
+/// 2.  `route_tree`- For paths with dynamic, regex or catch-all segments.
 ///
-/// When a request comes in, the matcher checks these categories in order to find the appropriate hook.
+/// Static routes are resolved with a single hash lookup. The route tree is walked one
+/// path segment at a time, so a lookup only visits the routes sharing a prefix with the
+/// request path.
 #[derive(Clone, CustomDebug, DisplayDebug, Getter, GetterMut, Setter)]
 pub struct RouteMatcher {
     /// A hash map for storing and quickly retrieving handlers for static route.
@@ -28,16 +71,32 @@ pub struct RouteMatcher {
     #[set(skip)]
     #[debug(skip)]
     pub(super) static_route: ServerHookMap,
-    /// A layered map of dynamic routes grouped by segment count.
-    /// Routes are organized by path segment count for efficient filtering during matching.
+    /// The tree holding the dynamic, regex and catch-all routes, indexed by path segment.
     #[get]
     #[set(skip)]
     #[debug(skip)]
-    pub(super) dynamic_route: ServerHookPatternRoute,
-    /// A layered map of regex routes grouped by segment count.
-    /// Routes with tail regex patterns can match paths with more segments.
-    #[get]
-    #[set(skip)]
+    pub(super) route_tree: RouteNode,
+}
+
+/// A set of routes sharing a path prefix and scoped middleware.
+///
+/// Middleware registered on a group only runs for the routes inside it, after the
+/// server's request middleware and before the server's response middleware. Groups
+/// can be nested, in which case the outer group's middleware wraps the inner one's.
+#[derive(Clone, CustomDebug, DisplayDebug, Getter)]
+pub struct RouteGroup {
+    /// The path prefix shared by the routes of the group.
+    pub(super) prefix: String,
+    /// The request middleware executed before the group's routes.
+    #[debug(skip)]
+    #[get(skip)]
+    pub(super) request_middleware: ServerHookList,
+    /// The response middleware executed after the group's routes.
+    #[debug(skip)]
+    #[get(skip)]
+    pub(super) response_middleware: ServerHookList,
+    /// The routes registered on the group, relative to its prefix.
     #[debug(skip)]
-    pub(super) regex_route: ServerHookPatternRoute,
+    #[get(skip)]
+    pub(super) route_list: RouteGroupList,
 }
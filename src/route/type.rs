@@ -10,7 +10,12 @@ pub type RouteParams = HashMapXxHash3_64<String, String>;
 /// This is used to represent a parsed route.
 pub type RouteSegmentList = Vec<RouteSegment>;
 
+/// A type alias for the routes collected by a `RouteGroup`.
+///
+/// Each entry holds the optional HTTP method, the route path and its hook.
+pub type RouteGroupList = Vec<(Option<Method>, String, ServerHookHandler)>;
+
 /// A type alias for a list of path components.
 ///
-/// This is often used for path components.
-pub(crate) type PathComponentList<'a> = Vec<&'a str>;
+/// Each component is paired with its byte offset in the path it was split from.
+pub(crate) type PathComponentList<'a> = Vec<(usize, &'a str)>;
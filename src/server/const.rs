@@ -0,0 +1,58 @@
+/// The initial delay in milliseconds before accepting again after resource exhaustion.
+pub const ACCEPT_BACKOFF_MIN_MS: u64 = 5;
+
+/// The maximum delay in milliseconds before accepting again after resource exhaustion.
+pub const ACCEPT_BACKOFF_MAX_MS: u64 = 1000;
+
+/// The Winsock error returned by accept when the process has too many open sockets.
+#[cfg(windows)]
+pub const WSAEMFILE: i32 = 10024;
+
+/// The Winsock error returned by accept when no buffer space is available.
+#[cfg(windows)]
+pub const WSAENOBUFS: i32 = 10055;
+
+/// The prefix marking a Unix domain socket bind address.
+pub const UNIX_ADDRESS_PREFIX: &str = "unix:";
+
+/// The environment variable holding the number of sockets passed by the service manager.
+pub const LISTEN_FDS: &str = "LISTEN_FDS";
+
+/// The environment variable holding the process id the passed sockets are meant for.
+pub const LISTEN_PID: &str = "LISTEN_PID";
+
+/// The environment variable holding the names of the sockets passed by the service manager.
+pub const LISTEN_FDNAMES: &str = "LISTEN_FDNAMES";
+
+/// The first file descriptor passed by the service manager.
+pub const LISTEN_FDS_START: i32 = 3;
+
+/// Whether listeners can share an address with `SO_REUSEPORT` on this platform.
+///
+/// The `reuseport` cfg is emitted by the build script on the platforms on which
+/// `socket2` provides `Socket::set_reuse_port`.
+pub(crate) const SUPPORTS_REUSEPORT: bool = cfg!(reuseport);
+
+/// The listen backlog used when `backlog` is not configured.
+pub const LISTEN_BACKLOG: u32 = 1024;
+
+/// The time in milliseconds allowed for a PROXY protocol header when no request header timeout is configured.
+pub const PROXY_HEADER_TIMEOUT_MS: u64 = 5000;
+
+/// The prefix of a PROXY protocol v1 header.
+pub const PROXY_V1_PREFIX: &[u8; 6] = b"PROXY ";
+
+/// The maximum length of a PROXY protocol v1 header, including the trailing CRLF.
+pub const PROXY_V1_MAX_LENGTH: usize = 107;
+
+/// The signature of a PROXY protocol v2 header.
+pub const PROXY_V2_SIGNATURE: &[u8; 12] = b"\r\n\r\n\0\r\nQUIT\n";
+
+/// The bytes ending the head of an HTTP request.
+pub const REQUEST_HEAD_END: &[u8] = b"\r\n\r\n";
+
+/// The initial delay in milliseconds between checks for a complete request head.
+pub const REQUEST_HEAD_POLL_MIN_MS: u64 = 1;
+
+/// The maximum delay in milliseconds between checks for a complete request head.
+pub const REQUEST_HEAD_POLL_MAX_MS: u64 = 50;
@@ -16,7 +16,10 @@ impl Default for Server {
             request_error: Vec::new(),
             route_matcher: RouteMatcher::new(),
             request_middleware: Vec::new(),
+            not_found: Vec::new(),
+            method_not_allowed: Vec::new(),
             response_middleware: Vec::new(),
+            accept_error: Vec::new(),
         }
     }
 }
@@ -42,27 +45,74 @@ impl PartialEq for Server {
             && self.get_task_panic().len() == other.get_task_panic().len()
             && self.get_request_error().len() == other.get_request_error().len()
             && self.get_request_middleware().len() == other.get_request_middleware().len()
+            && self.get_not_found().len() == other.get_not_found().len()
+            && self.get_method_not_allowed().len() == other.get_method_not_allowed().len()
             && self.get_response_middleware().len() == other.get_response_middleware().len()
+            && self.get_accept_error().len() == other.get_accept_error().len()
             && self
                 .get_task_panic()
                 .iter()
                 .zip(other.get_task_panic().iter())
-                .all(|pair: (&ServerHookHandler, &ServerHookHandler)| Arc::ptr_eq(pair.0, pair.1))
+                .all(
+                    |(left, right): (&(isize, ServerHookHandler), &(isize, ServerHookHandler))| {
+                        left.0 == right.0 && Arc::ptr_eq(&left.1, &right.1)
+                    },
+                )
             && self
                 .get_request_error()
                 .iter()
                 .zip(other.get_request_error().iter())
-                .all(|pair: (&ServerHookHandler, &ServerHookHandler)| Arc::ptr_eq(pair.0, pair.1))
+                .all(
+                    |(left, right): (&(isize, ServerHookHandler), &(isize, ServerHookHandler))| {
+                        left.0 == right.0 && Arc::ptr_eq(&left.1, &right.1)
+                    },
+                )
             && self
                 .get_request_middleware()
                 .iter()
                 .zip(other.get_request_middleware().iter())
-                .all(|pair: (&ServerHookHandler, &ServerHookHandler)| Arc::ptr_eq(pair.0, pair.1))
+                .all(
+                    |(left, right): (&(isize, ServerHookHandler), &(isize, ServerHookHandler))| {
+                        left.0 == right.0 && Arc::ptr_eq(&left.1, &right.1)
+                    },
+                )
+            && self
+                .get_not_found()
+                .iter()
+                .zip(other.get_not_found().iter())
+                .all(
+                    |(left, right): (&(isize, ServerHookHandler), &(isize, ServerHookHandler))| {
+                        left.0 == right.0 && Arc::ptr_eq(&left.1, &right.1)
+                    },
+                )
+            && self
+                .get_method_not_allowed()
+                .iter()
+                .zip(other.get_method_not_allowed().iter())
+                .all(
+                    |(left, right): (&(isize, ServerHookHandler), &(isize, ServerHookHandler))| {
+                        left.0 == right.0 && Arc::ptr_eq(&left.1, &right.1)
+                    },
+                )
             && self
                 .get_response_middleware()
                 .iter()
                 .zip(other.get_response_middleware().iter())
-                .all(|pair: (&ServerHookHandler, &ServerHookHandler)| Arc::ptr_eq(pair.0, pair.1))
+                .all(
+                    |(left, right): (&(isize, ServerHookHandler), &(isize, ServerHookHandler))| {
+                        left.0 == right.0 && Arc::ptr_eq(&left.1, &right.1)
+                    },
+                )
+            && self
+                .get_accept_error()
+                .iter()
+                .zip(other.get_accept_error().iter())
+                .all(
+                    |(left, right): (
+                        &(isize, AcceptErrorHookHandler),
+                        &(isize, AcceptErrorHookHandler),
+                    )| { left.0 == right.0 && Arc::ptr_eq(&left.1, &right.1) },
+                )
     }
 }
 
@@ -276,6 +326,99 @@ impl Lifetime for Server {
     }
 }
 
+/// Provides a default implementation for ConnectionTracker.
+impl Default for ConnectionTracker {
+    /// Creates a tracker with no connections and no shutdown in progress.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - A new `ConnectionTracker` instance.
+    #[inline(always)]
+    fn default() -> Self {
+        Self {
+            connection_count: channel(0).0,
+            shutting_down: channel(false).0,
+            force_close: channel(false).0,
+        }
+    }
+}
+
+/// Implementation block for ConnectionTracker.
+impl ConnectionTracker {
+    /// Returns the number of connections currently being served.
+    ///
+    /// # Returns
+    ///
+    /// - `usize` - The number of active connections.
+    #[inline(always)]
+    pub fn connection_count(&self) -> usize {
+        *self.connection_count.borrow()
+    }
+
+    /// Checks whether the server has started shutting down.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - `true` if connections should close after their current response.
+    #[inline(always)]
+    pub fn is_shutting_down(&self) -> bool {
+        *self.shutting_down.borrow()
+    }
+
+    /// Records a newly accepted connection.
+    #[inline(always)]
+    pub(crate) fn acquire(&self) {
+        self.connection_count
+            .send_modify(|count: &mut usize| *count += 1);
+    }
+
+    /// Records a finished connection.
+    #[inline(always)]
+    pub(crate) fn release(&self) {
+        self.connection_count
+            .send_modify(|count: &mut usize| *count = count.saturating_sub(1));
+    }
+
+    /// Signals connections to close after their current response.
+    #[inline(always)]
+    pub(crate) fn shutdown(&self) {
+        self.shutting_down.send_replace(true);
+    }
+
+    /// Signals connections to close immediately.
+    #[inline(always)]
+    pub(crate) fn close(&self) {
+        self.force_close.send_replace(true);
+    }
+
+    /// Waits until the server starts shutting down.
+    pub(crate) async fn wait_shutdown(&self) {
+        let _: Result<_, RecvError> = self
+            .shutting_down
+            .subscribe()
+            .wait_for(|shutting_down: &bool| *shutting_down)
+            .await;
+    }
+
+    /// Waits until connections are told to close immediately.
+    pub(crate) async fn wait_close(&self) {
+        let _: Result<_, RecvError> = self
+            .force_close
+            .subscribe()
+            .wait_for(|force_close: &bool| *force_close)
+            .await;
+    }
+
+    /// Waits until no connection is being served.
+    pub(crate) async fn wait_idle(&self) {
+        let _: Result<_, RecvError> = self
+            .connection_count
+            .subscribe()
+            .wait_for(|count: &usize| *count == 0)
+            .await;
+    }
+}
+
 /// Represents the server, providing methods to configure and run it.
 ///
 /// This struct wraps the `Server` configuration and routing logic,
@@ -290,32 +433,109 @@ impl Server {
     /// - `RequestError` - Added to request error handlers
     /// - `RequestMiddleware` - Added to pre-route middleware chain
     /// - `Route` - Registered as a route handler for the specified path
+    /// - `RouteMethod` - Registered as a route handler for the specified method and path
+    /// - `NotFound` - Added to not found handlers
+    /// - `MethodNotAllowed` - Added to method not allowed handlers
     /// - `ResponseMiddleware` - Added to post-route middleware chain
+    /// - `AcceptError` - Added to accept error handlers
+    ///
+    /// Hooks carrying a priority are inserted so that each list stays sorted by
+    /// descending priority, with `None` treated as `0` and ties kept in registration order.
     ///
     /// # Arguments
     ///
     /// - `HookType` - The `HookType` instance containing the hook configuration and factory.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if a `RouteMethod` hook names a method other than the standard
+    ///   uppercase ones, such as `get` or `FETCH`.
     #[inline]
     pub fn handle_hook(&mut self, hook: HookType) {
         match hook {
-            HookType::TaskPanic(_, hook) => {
-                self.get_mut_task_panic().push(hook());
+            HookType::TaskPanic(order, hook) => {
+                Hook::insert_by_order(self.get_mut_task_panic(), order, hook());
             }
-            HookType::RequestError(_, hook) => {
-                self.get_mut_request_error().push(hook());
+            HookType::RequestError(order, hook) => {
+                Hook::insert_by_order(self.get_mut_request_error(), order, hook());
             }
-            HookType::RequestMiddleware(_, hook) => {
-                self.get_mut_request_middleware().push(hook());
+            HookType::RequestMiddleware(order, hook) => {
+                Hook::insert_by_order(self.get_mut_request_middleware(), order, hook());
             }
             HookType::Route(path, hook) => {
                 self.get_mut_route_matcher().add(path, hook()).unwrap();
             }
-            HookType::ResponseMiddleware(_, hook) => {
-                self.get_mut_response_middleware().push(hook());
+            HookType::RouteMethod(method_name, path, hook) => {
+                let method: Method = method_name.parse().unwrap();
+                if let Method::Unknown(_) = method {
+                    panic!("Unknown HTTP method {method_name} for route {path}");
+                }
+                self.get_mut_route_matcher()
+                    .add_method(method, path, hook())
+                    .unwrap();
+            }
+            HookType::NotFound(order, hook) => {
+                Hook::insert_by_order(self.get_mut_not_found(), order, hook());
+            }
+            HookType::MethodNotAllowed(order, hook) => {
+                Hook::insert_by_order(self.get_mut_method_not_allowed(), order, hook());
+            }
+            HookType::ResponseMiddleware(order, hook) => {
+                Hook::insert_by_order(self.get_mut_response_middleware(), order, hook());
+            }
+            HookType::AcceptError(order, hook) => {
+                Hook::insert_by_order(self.get_mut_accept_error(), order, hook());
             }
         };
     }
 
+    /// Creates a server with every hook submitted through `inventory`.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - A default server with all collected hooks registered.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the same hook, with the same type and handler, is collected twice
+    ///   with the same explicit priority.
+    /// - Panics if two collected routes share the same path pattern.
+    /// - Panics if a collected method route names an unknown HTTP method.
+    #[inline]
+    pub fn from_inventory() -> Self {
+        let mut server: Self = Self::default();
+        server.register_collected_hooks();
+        server
+    }
+
+    /// Registers every hook submitted through `inventory`.
+    ///
+    /// Hooks are validated with `HookType::assert_unique_order` before any of them
+    /// is installed, then dispatched through `handle_hook`, which keeps each hook
+    /// list sorted by priority. Distinct hooks may share a priority. Such hooks run
+    /// in collection order, which `inventory` leaves unspecified, so give hooks whose
+    /// relative order matters distinct priorities.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Self` - Reference to self for method chaining.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the same hook, with the same type and handler, is collected twice
+    ///   with the same explicit priority.
+    /// - Panics if two collected routes share the same path pattern.
+    /// - Panics if a collected method route names an unknown HTTP method.
+    #[inline]
+    pub fn register_collected_hooks(&mut self) -> &mut Self {
+        let hook_list: Vec<HookType> = inventory::iter::<HookType>().copied().collect();
+        HookType::assert_unique_order(hook_list.clone());
+        hook_list
+            .into_iter()
+            .for_each(|hook: HookType| self.handle_hook(hook));
+        self
+    }
+
     /// Sets the server configuration from a JSON string.
     ///
     /// # Arguments
@@ -378,7 +598,27 @@ impl Server {
     where
         S: ServerHook,
     {
-        self.get_mut_task_panic().push(Hook::factory::<S>());
+        self.task_panic_with_order::<S>(0)
+    }
+
+    /// Registers a task panic handler with an explicit priority.
+    ///
+    /// Handlers with higher priority execute first. Handlers with equal
+    /// priority execute in registration order.
+    ///
+    /// # Arguments
+    ///
+    /// - `isize` - The priority of the handler.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Self` - Reference to self for method chaining.
+    #[inline(always)]
+    pub fn task_panic_with_order<S>(&mut self, order: isize) -> &mut Self
+    where
+        S: ServerHook,
+    {
+        Hook::insert_by_order(self.get_mut_task_panic(), Some(order), Hook::factory::<S>());
         self
     }
 
@@ -395,7 +635,31 @@ impl Server {
     where
         S: ServerHook,
     {
-        self.get_mut_request_error().push(Hook::factory::<S>());
+        self.request_error_with_order::<S>(0)
+    }
+
+    /// Registers a request error handler with an explicit priority.
+    ///
+    /// Handlers with higher priority execute first. Handlers with equal
+    /// priority execute in registration order.
+    ///
+    /// # Arguments
+    ///
+    /// - `isize` - The priority of the handler.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Self` - Reference to self for method chaining.
+    #[inline(always)]
+    pub fn request_error_with_order<S>(&mut self, order: isize) -> &mut Self
+    where
+        S: ServerHook,
+    {
+        Hook::insert_by_order(
+            self.get_mut_request_error(),
+            Some(order),
+            Hook::factory::<S>(),
+        );
         self
     }
 
@@ -422,6 +686,112 @@ impl Server {
         self
     }
 
+    /// Registers a route hook for a specific HTTP method and path.
+    ///
+    /// The hook only runs for requests using the given method. A hook registered
+    /// through `route` on the same path still handles all other methods.
+    ///
+    /// # Arguments
+    ///
+    /// - `Method` - The HTTP method the route responds to.
+    /// - `AsRef<str>` - The route path pattern.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Self` - Reference to self for method chaining.
+    #[inline(always)]
+    pub fn route_method<S>(&mut self, method: Method, path: impl AsRef<str>) -> &mut Self
+    where
+        S: ServerHook,
+    {
+        self.get_mut_route_matcher()
+            .add_method(method, path.as_ref(), Hook::factory::<S>())
+            .unwrap();
+        self
+    }
+
+    /// Registers a group of routes sharing a path prefix and scoped middleware.
+    ///
+    /// # Arguments
+    ///
+    /// - `AsRef<str>` - The path prefix shared by the routes of the group.
+    /// - `FnOnce(&mut RouteGroup)` - The function registering the group's routes and middleware.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Self` - Reference to self for method chaining.
+    pub fn group<F>(&mut self, prefix: impl AsRef<str>, register: F) -> &mut Self
+    where
+        F: FnOnce(&mut RouteGroup),
+    {
+        let mut group: RouteGroup = RouteGroup::new(prefix);
+        register(&mut group);
+        for (method, path, hook) in group.into_route_list() {
+            self.get_mut_route_matcher()
+                .insert(&path, method, hook)
+                .unwrap();
+        }
+        self
+    }
+
+    /// Mounts the routes and hooks of another server under a path prefix.
+    ///
+    /// The following is carried over from the other server:
+    /// - Its routes, registered under the prefix.
+    /// - Its request and response middleware, which only run for its own routes.
+    /// - Its not found and method not allowed hooks, which only run for request paths
+    ///   under the prefix. They are merged by priority, after hooks of the same priority
+    ///   already registered here.
+    ///
+    /// Its task panic, request error and accept error hooks, as well as its configuration,
+    /// are not carried over. Nothing is merged if one of the routes conflicts with an
+    /// existing route.
+    ///
+    /// # Arguments
+    ///
+    /// - `AsRef<str>` - The path prefix of the mounted routes.
+    /// - `Server` - The server to mount.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<&mut Self, RouteError>` - Reference to self for method chaining, or
+    ///   `RouteError::DuplicatePattern` if a mounted route is already registered.
+    pub fn mount(
+        &mut self,
+        prefix: impl AsRef<str>,
+        server: Server,
+    ) -> Result<&mut Self, RouteError> {
+        let group: RouteGroup = RouteGroup::from_parts(
+            prefix,
+            server.get_route_matcher().get_route_list(),
+            server.request_middleware,
+            server.response_middleware,
+        );
+        let scope = |hook_list: ServerHookList| -> ServerHookList {
+            hook_list
+                .into_iter()
+                .map(|(order, hook)| (order, group.scope_hook(hook)))
+                .collect()
+        };
+        let not_found: ServerHookList = scope(server.not_found);
+        let method_not_allowed: ServerHookList = scope(server.method_not_allowed);
+        let mut route_matcher: RouteMatcher = self.get_route_matcher().clone();
+        for (method, path, hook) in group.into_route_list() {
+            route_matcher.insert(&path, method, hook)?;
+        }
+        self.route_matcher = route_matcher;
+        let hook_list_pairs: [(&mut ServerHookList, ServerHookList); 2] = [
+            (&mut self.not_found, not_found),
+            (&mut self.method_not_allowed, method_not_allowed),
+        ];
+        for (target, hook_list) in hook_list_pairs {
+            for (order, hook) in hook_list {
+                Hook::insert_by_order(target, Some(order), hook);
+            }
+        }
+        Ok(self)
+    }
+
     /// Registers request middleware to the processing pipeline.
     ///
     /// This method allows registering middleware that implements the `ServerHook` trait,
@@ -435,7 +805,31 @@ impl Server {
     where
         S: ServerHook,
     {
-        self.get_mut_request_middleware().push(Hook::factory::<S>());
+        self.request_middleware_with_order::<S>(0)
+    }
+
+    /// Registers a request middleware handler with an explicit priority.
+    ///
+    /// Handlers with higher priority execute first. Handlers with equal
+    /// priority execute in registration order.
+    ///
+    /// # Arguments
+    ///
+    /// - `isize` - The priority of the handler.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Self` - Reference to self for method chaining.
+    #[inline(always)]
+    pub fn request_middleware_with_order<S>(&mut self, order: isize) -> &mut Self
+    where
+        S: ServerHook,
+    {
+        Hook::insert_by_order(
+            self.get_mut_request_middleware(),
+            Some(order),
+            Hook::factory::<S>(),
+        );
         self
     }
 
@@ -452,8 +846,154 @@ impl Server {
     where
         S: ServerHook,
     {
-        self.get_mut_response_middleware()
-            .push(Hook::factory::<S>());
+        self.response_middleware_with_order::<S>(0)
+    }
+
+    /// Registers a response middleware handler with an explicit priority.
+    ///
+    /// Handlers with higher priority execute first. Handlers with equal
+    /// priority execute in registration order.
+    ///
+    /// # Arguments
+    ///
+    /// - `isize` - The priority of the handler.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Self` - Reference to self for method chaining.
+    #[inline(always)]
+    pub fn response_middleware_with_order<S>(&mut self, order: isize) -> &mut Self
+    where
+        S: ServerHook,
+    {
+        Hook::insert_by_order(
+            self.get_mut_response_middleware(),
+            Some(order),
+            Hook::factory::<S>(),
+        );
+        self
+    }
+
+    /// Registers a not found handler to the processing pipeline.
+    ///
+    /// This method allows registering handlers that implement the `ServerHook` trait,
+    /// which will be executed instead of a route hook when no route matches the
+    /// request path. The response already carries the `404 Not Found` status when they run.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Self` - Reference to self for method chaining.
+    #[inline(always)]
+    pub fn not_found<S>(&mut self) -> &mut Self
+    where
+        S: ServerHook,
+    {
+        self.not_found_with_order::<S>(0)
+    }
+
+    /// Registers a not found handler with an explicit priority.
+    ///
+    /// Handlers with higher priority execute first. Handlers with equal
+    /// priority execute in registration order.
+    ///
+    /// # Arguments
+    ///
+    /// - `isize` - The priority of the handler.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Self` - Reference to self for method chaining.
+    #[inline(always)]
+    pub fn not_found_with_order<S>(&mut self, order: isize) -> &mut Self
+    where
+        S: ServerHook,
+    {
+        Hook::insert_by_order(self.get_mut_not_found(), Some(order), Hook::factory::<S>());
+        self
+    }
+
+    /// Registers a method not allowed handler to the processing pipeline.
+    ///
+    /// This method allows registering handlers that implement the `ServerHook` trait,
+    /// which will be executed instead of a route hook when the request path matches
+    /// a route but its method does not. The response already carries the
+    /// `405 Method Not Allowed` status and the `Allow` header when they run.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Self` - Reference to self for method chaining.
+    #[inline(always)]
+    pub fn method_not_allowed<S>(&mut self) -> &mut Self
+    where
+        S: ServerHook,
+    {
+        self.method_not_allowed_with_order::<S>(0)
+    }
+
+    /// Registers a method not allowed handler with an explicit priority.
+    ///
+    /// Handlers with higher priority execute first. Handlers with equal
+    /// priority execute in registration order.
+    ///
+    /// # Arguments
+    ///
+    /// - `isize` - The priority of the handler.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Self` - Reference to self for method chaining.
+    #[inline(always)]
+    pub fn method_not_allowed_with_order<S>(&mut self, order: isize) -> &mut Self
+    where
+        S: ServerHook,
+    {
+        Hook::insert_by_order(
+            self.get_mut_method_not_allowed(),
+            Some(order),
+            Hook::factory::<S>(),
+        );
+        self
+    }
+
+    /// Registers an accept error handler to the processing pipeline.
+    ///
+    /// This method allows registering handlers that implement the `AcceptErrorHook` trait,
+    /// which will be executed whenever accepting a connection fails, including when
+    /// its PROXY protocol header is rejected.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Self` - Reference to self for method chaining.
+    #[inline(always)]
+    pub fn accept_error<S>(&mut self) -> &mut Self
+    where
+        S: AcceptErrorHook,
+    {
+        self.accept_error_with_order::<S>(0)
+    }
+
+    /// Registers an accept error handler with an explicit priority.
+    ///
+    /// Handlers with higher priority execute first. Handlers with equal
+    /// priority execute in registration order.
+    ///
+    /// # Arguments
+    ///
+    /// - `isize` - The priority of the handler.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Self` - Reference to self for method chaining.
+    #[inline(always)]
+    pub fn accept_error_with_order<S>(&mut self, order: isize) -> &mut Self
+    where
+        S: AcceptErrorHook,
+    {
+        Hook::insert_by_order(
+            self.get_mut_accept_error(),
+            Some(order),
+            Hook::accept_error_factory::<S>(),
+        );
         self
     }
 
@@ -563,7 +1103,7 @@ impl Server {
                 .get_mut_response()
                 .set_status_code(HttpStatus::InternalServerError.code());
             stream.set_closed(false);
-            for hook in self.get_task_panic().iter() {
+            for (_, hook) in self.get_task_panic().iter() {
                 if hook(stream, ctx).await.is_reject() {
                     break;
                 }
@@ -577,7 +1117,8 @@ impl Server {
 
     /// Configures socket options for a newly accepted `TcpStream`.
     ///
-    /// This applies settings like `TCP_NODELAY`, and `IP_TTL` from the server's configuration.
+    /// This applies settings like `TCP_NODELAY`, `IP_TTL`, TCP keepalive, socket buffer
+    /// sizes and `SO_LINGER` from the server's configuration.
     ///
     /// # Arguments
     ///
@@ -590,8 +1131,22 @@ impl Server {
         if let Some(ttl) = config.try_get_ttl() {
             let _: Result<(), std::io::Error> = stream.set_ttl(*ttl);
         }
-    }
-
+        let socket: SockRef = SockRef::from(stream);
+        if let Some(keepalive) = config.try_get_tcp_keepalive() {
+            let _: Result<(), std::io::Error> = socket.set_tcp_keepalive(&keepalive);
+        }
+        if let Some(recv_buffer_size) = config.try_get_recv_buffer_size() {
+            let _: Result<(), std::io::Error> = socket.set_recv_buffer_size(*recv_buffer_size);
+        }
+        if let Some(send_buffer_size) = config.try_get_send_buffer_size() {
+            let _: Result<(), std::io::Error> = socket.set_send_buffer_size(*send_buffer_size);
+        }
+        if let Some(linger_secs) = config.try_get_linger_secs() {
+            let _: Result<(), std::io::Error> =
+                socket.set_linger(Some(Duration::from_secs(*linger_secs)));
+        }
+    }
+
     /// Executes trait-based request middleware in sequence.
     ///
     /// # Arguments
@@ -607,7 +1162,7 @@ impl Server {
         stream: &mut Stream,
         ctx: &mut Context,
     ) -> bool {
-        for hook in self.get_request_middleware().iter() {
+        for (_, hook) in self.get_request_middleware().iter() {
             if hook(stream, ctx).await.is_reject() {
                 return true;
             }
@@ -617,6 +1172,9 @@ impl Server {
 
     /// Executes a trait-based route hook if one matches.
     ///
+    /// Falls back to the method not allowed hooks when the path matches a route
+    /// but not the request method, and to the not found hooks when nothing matches.
+    ///
     /// # Arguments
     ///
     /// - `&mut Stream` - The `Stream` for the current request.
@@ -632,10 +1190,77 @@ impl Server {
         ctx: &mut Context,
         path: &str,
     ) -> bool {
-        if let Some(hook) = self.get_route_matcher().try_resolve_route(ctx, path)
-            && hook(stream, ctx).await.is_reject()
-        {
-            return true;
+        if let Some(hook) = self.get_route_matcher().try_resolve_route(ctx, path) {
+            return hook(stream, ctx).await.is_reject();
+        }
+        let allowed_methods: Vec<Method> = self.get_route_matcher().get_allowed_methods(path);
+        if !allowed_methods.is_empty() {
+            return self
+                .handle_method_not_allowed(stream, ctx, &allowed_methods)
+                .await;
+        }
+        self.handle_not_found(stream, ctx).await
+    }
+
+    /// Prepares a `404 Not Found` response and executes the not found hooks.
+    ///
+    /// This only runs when no route matches the request path. Hooks may override the response.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut Stream` - The `Stream` for the current request.
+    /// - `&mut Context` - The `Context` for the current request.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - `true` if the lifecycle was aborted, `false` otherwise.
+    pub(super) async fn handle_not_found(&self, stream: &mut Stream, ctx: &mut Context) -> bool {
+        let status_code: ResponseStatusCode = HttpStatus::NotFound.code();
+        ctx.get_mut_response()
+            .set_status_code(status_code)
+            .set_reason_phrase(HttpStatus::phrase(status_code));
+        for (_, hook) in self.get_not_found().iter() {
+            if hook(stream, ctx).await.is_reject() {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Prepares a `405 Method Not Allowed` response and executes the method not allowed hooks.
+    ///
+    /// The `Allow` header is computed from the routes registered on the matched path,
+    /// so it always reflects the actual registrations. Hooks may override the response.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut Stream` - The `Stream` for the current request.
+    /// - `&mut Context` - The `Context` for the current request.
+    /// - `&[Method]` - The methods registered on the matched path.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - `true` if the lifecycle was aborted, `false` otherwise.
+    pub(super) async fn handle_method_not_allowed(
+        &self,
+        stream: &mut Stream,
+        ctx: &mut Context,
+        allowed_methods: &[Method],
+    ) -> bool {
+        let allow: String = allowed_methods
+            .iter()
+            .map(|method: &Method| method.to_string())
+            .collect::<Vec<String>>()
+            .join(COMMA);
+        let status_code: ResponseStatusCode = HttpStatus::MethodNotAllowed.code();
+        ctx.get_mut_response()
+            .set_status_code(status_code)
+            .set_reason_phrase(HttpStatus::phrase(status_code))
+            .set_header(ALLOW, allow);
+        for (_, hook) in self.get_method_not_allowed().iter() {
+            if hook(stream, ctx).await.is_reject() {
+                return true;
+            }
         }
         false
     }
@@ -655,7 +1280,7 @@ impl Server {
         stream: &mut Stream,
         ctx: &mut Context,
     ) -> bool {
-        for hook in self.get_response_middleware().iter() {
+        for (_, hook) in self.get_response_middleware().iter() {
             if hook(stream, ctx).await.is_reject() {
                 return true;
             }
@@ -678,7 +1303,7 @@ impl Server {
     ) {
         ctx.set_request_error_data(error.clone());
         stream.set_closed(false);
-        for hook in self.get_request_error().iter() {
+        for (_, hook) in self.get_request_error().iter() {
             if hook(stream, ctx).await.is_reject() {
                 return;
             }
@@ -695,15 +1320,19 @@ impl Server {
     /// - `&mut Stream` - The `Stream` for the current request.
     /// - `&mut Context` - The `Context` for the current request.
     /// - `&Request` - The incoming request to be processed.
+    /// - `&ConnectionTracker` - The tracker of the server's connections.
     ///
     /// # Returns
     ///
     /// - `bool` - A boolean indicating whether the connection should be kept alive.
+    ///   Always `false` once the server is shutting down, in which case the response
+    ///   also carries `Connection: close`.
     async fn request_hook(
         &self,
         stream: &mut Stream,
         ctx: &mut Context,
         request: &Request,
+        tracker: &ConnectionTracker,
     ) -> bool {
         let mut response: Response = Response::default();
         response.set_version(request.get_version().clone());
@@ -714,16 +1343,153 @@ impl Server {
         stream.set_closed(false);
         let keep_alive: bool = request.is_enable_keep_alive();
         if self.handle_request_middleware(stream, ctx).await {
-            return stream.is_keep_alive(keep_alive);
+            return stream.is_keep_alive(keep_alive && !tracker.is_shutting_down());
         }
         let route: &str = request.get_path();
         if self.handle_route_matcher(stream, ctx, route).await {
-            return stream.is_keep_alive(keep_alive);
+            return stream.is_keep_alive(keep_alive && !tracker.is_shutting_down());
+        }
+        if tracker.is_shutting_down() {
+            ctx.get_mut_response().set_header(CONNECTION, CLOSE);
         }
         if self.handle_response_middleware(stream, ctx).await {
-            return stream.is_keep_alive(keep_alive);
+            return stream.is_keep_alive(keep_alive && !tracker.is_shutting_down());
+        }
+        stream.is_keep_alive(keep_alive && !tracker.is_shutting_down())
+    }
+
+    /// Waits until the head of the next request is buffered on the stream.
+    ///
+    /// The received bytes are peeked, so they are left for the request parser. The wait
+    /// also ends once the peeked bytes fill `buffer_size` or the peer closes the connection,
+    /// leaving the request parser to handle what arrived.
+    ///
+    /// # Arguments
+    ///
+    /// - `&TcpStream` - The stream the request arrives on.
+    /// - `usize` - The maximum number of bytes to peek.
+    async fn wait_request_head(stream: &TcpStream, buffer_size: usize) {
+        let mut buffer: Vec<u8> = vec![0; buffer_size.max(REQUEST_HEAD_END.len())];
+        let mut delay_ms: u64 = REQUEST_HEAD_POLL_MIN_MS;
+        loop {
+            let length: usize = match stream.peek(&mut buffer).await {
+                Ok(length) => length,
+                Err(_) => return,
+            };
+            if length == 0
+                || length == buffer.len()
+                || buffer[..length]
+                    .windows(REQUEST_HEAD_END.len())
+                    .any(|window: &[u8]| window == REQUEST_HEAD_END)
+            {
+                return;
+            }
+            sleep(Duration::from_millis(delay_ms)).await;
+            delay_ms = (delay_ms * 2).min(REQUEST_HEAD_POLL_MAX_MS);
+        }
+    }
+
+    /// Discards the bytes already buffered on the stream.
+    ///
+    /// Closing a connection with unread bytes resets it, which would drop the response
+    /// sent to a request whose head did not arrive in time.
+    ///
+    /// # Arguments
+    ///
+    /// - `&TcpStream` - The stream to discard the buffered bytes of.
+    fn discard_buffered(stream: &TcpStream) {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        while let Ok(length) = stream.try_read(&mut buffer) {
+            if length == 0 {
+                break;
+            }
+        }
+    }
+
+    /// Reads the next HTTP request while enforcing the configured connection timeouts.
+    ///
+    /// The wait for the request to start arriving is bounded by the timeout matching
+    /// `ConnectionTimeout`. Receiving the whole request head is bounded by the request
+    /// header timeout, counted from the start of the wait on a new connection and from
+    /// the first byte of the request on a keep-alive connection. Reading the request is
+    /// bounded by the read timeout of the stream's `RequestConfig`. When one of them
+    /// elapses, the kind is stored in the context.
+    ///
+    /// Only the wait for the request to start arriving is interrupted when the server
+    /// starts shutting down. A request whose bytes have started arriving is read in full.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut Stream` - The `Stream` to read from.
+    /// - `&mut Context` - The `Context` of the connection.
+    /// - `ConnectionTimeout` - The timeout bounding the wait for the request to start arriving.
+    /// - `&ConnectionTracker` - The tracker of the server's connections.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Result<Request, RequestError>>` - The request, `RequestError::ReadTimeout` if a
+    ///   timeout elapsed, or `None` if the server started shutting down while the connection was idle.
+    async fn read_http_request(
+        &self,
+        stream: &mut Stream,
+        ctx: &mut Context,
+        wait_timeout: ConnectionTimeout,
+        tracker: &ConnectionTracker,
+    ) -> Option<Result<Request, RequestError>> {
+        let config: &ServerConfig = self.get_server_config();
+        let header_timeout_ms: Option<u64> = *config.try_get_request_header_timeout_ms();
+        let wait_timeout_ms: Option<u64> = match wait_timeout {
+            ConnectionTimeout::KeepAlive => *config.try_get_keep_alive_timeout_ms(),
+            _ => header_timeout_ms,
+        };
+        let wait_deadline: Option<Instant> = wait_timeout_ms
+            .map(|wait_timeout_ms: u64| Instant::now() + Duration::from_millis(wait_timeout_ms));
+        let readable = async {
+            match wait_deadline {
+                Some(wait_deadline) => timeout_at(wait_deadline, stream.get_stream().readable())
+                    .await
+                    .map(|_| ()),
+                None => {
+                    let _: Result<(), std::io::Error> = stream.get_stream().readable().await;
+                    Ok(())
+                }
+            }
+        };
+        select! {
+            biased;
+            result = readable => {
+                if let Err(error) = result {
+                    ctx.set_connection_timeout(Some(wait_timeout));
+                    return Some(Err(error.into()));
+                }
+            }
+            _ = tracker.wait_shutdown() => return None,
+        }
+        let header_deadline: Option<Instant> = match wait_timeout {
+            ConnectionTimeout::KeepAlive => header_timeout_ms.map(|header_timeout_ms: u64| {
+                Instant::now() + Duration::from_millis(header_timeout_ms)
+            }),
+            _ => wait_deadline,
+        };
+        if let Some(header_deadline) = header_deadline
+            && let Err(error) = timeout_at(
+                header_deadline,
+                Self::wait_request_head(
+                    stream.get_stream(),
+                    self.get_request_config().get_buffer_size(),
+                ),
+            )
+            .await
+        {
+            Self::discard_buffered(stream.get_stream());
+            ctx.set_connection_timeout(Some(ConnectionTimeout::RequestHeader));
+            return Some(Err(error.into()));
+        }
+        let result: Result<Request, RequestError> = stream.try_get_http_request().await;
+        if let Err(RequestError::ReadTimeout(_)) = result {
+            ctx.set_connection_timeout(Some(ConnectionTimeout::RequestRead));
         }
-        stream.is_keep_alive(keep_alive)
+        Some(result)
     }
 
     /// Handles subsequent HTTP requests on a persistent (keep-alive) connection.
@@ -733,19 +1499,30 @@ impl Server {
     /// - `&mut Stream` - The `Stream` for the current request.
     /// - `&mut Context` - The `Context` for the current request.
     /// - `&Request` - The initial request that established the keep-alive connection.
+    /// - `&ConnectionTracker` - The tracker of the server's connections.
+    ///
+    /// Once the server starts shutting down, an idle connection is closed instead of
+    /// waiting for its next request, while a request already arriving is still served.
     async fn handle_http_requests(
         &self,
         stream: &mut Stream,
         ctx: &mut Context,
         request: &Request,
+        tracker: &ConnectionTracker,
     ) {
-        if !self.request_hook(stream, ctx, request).await {
+        if !self.request_hook(stream, ctx, request, tracker).await {
             return;
         }
         loop {
-            match stream.try_get_http_request().await {
+            let Some(result) = self
+                .read_http_request(stream, ctx, ConnectionTimeout::KeepAlive, tracker)
+                .await
+            else {
+                return;
+            };
+            match result {
                 Ok(new_request) => {
-                    if !self.request_hook(stream, ctx, &new_request).await {
+                    if !self.request_hook(stream, ctx, &new_request, tracker).await {
                         return;
                     }
                 }
@@ -760,24 +1537,40 @@ impl Server {
     /// Handles a single client connection, determining whether it's an HTTP or WebSocket request.
     ///
     /// It reads the initial request from the stream and dispatches it to the appropriate hook.
+    /// The connection is dropped as soon as the tracker signals an immediate close.
     ///
     /// # Arguments
     ///
     /// - `&mut Stream` - The `Stream` for the current request.
     /// - `&mut Context` - The `Context` for the current request.
+    /// - `ConnectionTracker` - The tracker of the server's connections.
     ///
     /// # Safety
     ///
     /// - The `ctx` is a valid pointer to a `Context` that was
     ///   originally created via `Box::into_raw` and is now being reclaimed.
-    async fn handle_connection(&self, stream: &mut Stream, ctx: &mut Context) {
-        match stream.try_get_http_request().await {
-            Ok(request) => {
-                self.handle_http_requests(stream, ctx, &request).await;
-            }
-            Err(error) => {
-                self.handle_request_error(stream, ctx, &error).await;
-            }
+    async fn handle_connection(
+        &self,
+        stream: &mut Stream,
+        ctx: &mut Context,
+        tracker: ConnectionTracker,
+    ) {
+        select! {
+            _ = async {
+                match self
+                    .read_http_request(stream, ctx, ConnectionTimeout::RequestHeader, &tracker)
+                    .await
+                {
+                    Some(Ok(request)) => {
+                        self.handle_http_requests(stream, ctx, &request, &tracker).await;
+                    }
+                    Some(Err(error)) => {
+                        self.handle_request_error(stream, ctx, &error).await;
+                    }
+                    None => {}
+                }
+            } => {}
+            _ = tracker.wait_close() => {}
         }
         unsafe {
             let _: Box<Context> = Box::from_raw(ctx);
@@ -790,43 +1583,549 @@ impl Server {
     /// # Arguments
     ///
     /// - `&TcpListener` - A reference to the `TcpListener` to accept connections from.
-    async fn tcp_accept(&'static self, tcp_listener: &TcpListener) {
+    /// - `&ConnectionTracker` - The tracker recording every accepted connection.
+    /// - `Option<Arc<Semaphore>>` - The connection slots shared by every listener, if limited.
+    ///
+    /// When a non-zero `max_connections` is configured, accepting pauses until a served
+    /// connection finishes and frees a slot.
+    ///
+    /// Accept errors are passed to the accept error hooks. Resource exhaustion is
+    /// retried after a bounded exponential backoff, and a fatal error ends the loop.
+    async fn tcp_accept(
+        &'static self,
+        tcp_listener: &TcpListener,
+        tracker: &ConnectionTracker,
+        semaphore: Option<Arc<Semaphore>>,
+    ) {
+        let mut backoff_ms: u64 = 0;
         loop {
-            if let Ok((stream, _)) = tcp_listener.accept().await {
-                self.configure_stream(&stream);
-                let request_config: RequestConfig = *self.get_request_config();
-                let stream: &'static mut Stream =
-                    Box::leak(Box::new(Stream::new(stream, request_config, false)));
-                let ctx: &'static mut Context = Box::leak(Box::new(Context::default()));
-                spawn(self.task_handler(
-                    stream.into(),
-                    ctx.into(),
-                    self.handle_connection(stream, ctx),
-                ));
+            let permit: Option<OwnedSemaphorePermit> = match &semaphore {
+                Some(semaphore) => semaphore.clone().acquire_owned().await.ok(),
+                None => None,
+            };
+            let stream: TcpStream = match tcp_listener.accept().await {
+                Ok((stream, _)) => {
+                    backoff_ms = 0;
+                    stream
+                }
+                Err(error) => {
+                    let accept_error: AcceptError = (&error).into();
+                    self.handle_accept_error(&accept_error).await;
+                    match accept_error {
+                        AcceptError::Transient(_) | AcceptError::ProxyProtocol(_) => {}
+                        AcceptError::ResourceExhausted(_) => {
+                            backoff_ms = (backoff_ms * 2)
+                                .clamp(ACCEPT_BACKOFF_MIN_MS, ACCEPT_BACKOFF_MAX_MS);
+                            drop(permit);
+                            sleep(Duration::from_millis(backoff_ms)).await;
+                        }
+                        AcceptError::Fatal(_) => return,
+                    }
+                    continue;
+                }
+            };
+            self.configure_stream(&stream);
+            let tracker: ConnectionTracker = tracker.clone();
+            tracker.acquire();
+            spawn(async move {
+                self.serve_connection(stream, tracker.clone()).await;
+                tracker.release();
+                drop(permit);
+            });
+        }
+    }
+
+    /// Serves an accepted connection until it is closed.
+    ///
+    /// When PROXY protocol is enabled, the header is read before the connection is
+    /// handed to the HTTP layer. Connections without a valid header are dropped after
+    /// the accept error hooks run with `AcceptError::ProxyProtocol`.
+    ///
+    /// # Arguments
+    ///
+    /// - `TcpStream` - The accepted connection.
+    /// - `ConnectionTracker` - The tracker of the server's connections.
+    async fn serve_connection(&'static self, mut stream: TcpStream, tracker: ConnectionTracker) {
+        let mut proxy_header: Option<ProxyHeader> = None;
+        if self.get_server_config().try_get_proxy_protocol() == &Some(true) {
+            match self.read_proxy_header(&mut stream).await {
+                Ok(header) => proxy_header = Some(header),
+                Err(error) => {
+                    self.handle_accept_error(&AcceptError::ProxyProtocol(error))
+                        .await;
+                    return;
+                }
+            }
+        }
+        let mut request_config: RequestConfig = *self.get_request_config();
+        if let Some(request_read_timeout_ms) =
+            self.get_server_config().try_get_request_read_timeout_ms()
+        {
+            request_config.set_read_timeout_ms(*request_read_timeout_ms);
+        }
+        let stream: &'static mut Stream =
+            Box::leak(Box::new(Stream::new(stream, request_config, false)));
+        let ctx: &'static mut Context = Box::leak(Box::new(Context::default()));
+        if let Some(header) = proxy_header {
+            ctx.set_client_address(*header.try_get_source_address());
+            ctx.set_destination_address(*header.try_get_destination_address());
+        }
+        self.task_handler(
+            stream.into(),
+            ctx.into(),
+            self.handle_connection(stream, ctx, tracker),
+        )
+        .await;
+    }
+
+    /// Reads the PROXY protocol header of an accepted connection.
+    ///
+    /// The header must arrive within the request header timeout, or within
+    /// `PROXY_HEADER_TIMEOUT_MS` when none is configured.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut TcpStream` - The accepted connection.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<ProxyHeader, ProxyProtocolError>` - The parsed header, or why it was rejected.
+    async fn read_proxy_header(
+        &self,
+        stream: &mut TcpStream,
+    ) -> Result<ProxyHeader, ProxyProtocolError> {
+        let timeout_ms: u64 = self
+            .get_server_config()
+            .try_get_request_header_timeout_ms()
+            .unwrap_or(PROXY_HEADER_TIMEOUT_MS);
+        timeout(Duration::from_millis(timeout_ms), ProxyHeader::read(stream))
+            .await
+            .unwrap_or(Err(ProxyProtocolError::Timeout))
+    }
+
+    /// Executes the accept error hooks in sequence.
+    ///
+    /// # Arguments
+    ///
+    /// - `&AcceptError` - The error returned while accepting.
+    async fn handle_accept_error(&self, error: &AcceptError) {
+        for (_, hook) in self.get_accept_error().iter() {
+            if hook(error).await.is_reject() {
+                return;
             }
         }
     }
 
-    /// Starts the server, binds to the configured address, and begins listening for connections.
+    /// Drains the tracked connections after the server stops accepting.
+    ///
+    /// Connections are told to close after their current response. If the configured
+    /// shutdown timeout elapses first, the remaining connections are closed immediately.
+    ///
+    /// # Arguments
+    ///
+    /// - `&ConnectionTracker` - The tracker of the connections to drain.
+    async fn drain_connections(&self, tracker: &ConnectionTracker) {
+        tracker.shutdown();
+        if let Some(shutdown_timeout_ms) = self.get_server_config().try_get_shutdown_timeout_ms()
+            && timeout(
+                Duration::from_millis(*shutdown_timeout_ms),
+                tracker.wait_idle(),
+            )
+            .await
+            .is_err()
+        {
+            tracker.close();
+        }
+        tracker.wait_idle().await;
+    }
+
+    /// Starts the server, binds to the configured addresses, and begins listening for connections.
     ///
     /// This is the main entry point to launch the server. It will initialize the panic hook,
-    /// create a TCP listener, and then enter the connection acceptance loop in a background task.
+    /// create a TCP listener for `address` and every additional address, and then enter
+    /// a connection acceptance loop per listener in background tasks. Binding stops at the
+    /// first address that fails, before any connection is accepted.
+    ///
+    /// Unix domain socket addresses (`unix:/path/to.sock`) are rejected with
+    /// `ServerError::UnsupportedAddress`, because `Stream` only wraps a `TcpStream`.
     ///
     /// # Returns
     ///
     /// Returns a `Result` containing a shutdown function on success.
-    /// Calling this function will shut down the server by aborting its main task.
+    /// Calling this function stops accepting connections and drains the open ones;
+    /// waiting on it resolves once they are all closed.
     /// Returns an error if the server fails to start.
     pub async fn run(&self) -> Result<ServerControlHook, ServerError> {
-        let bind_address: &String = self.get_server_config().get_address();
-        let tcp_listener: TcpListener = TcpListener::bind(&bind_address).await?;
+        Ok(self.serve(self.bind_addresses().await?))
+    }
+
+    /// Binds the configured addresses without serving them.
+    ///
+    /// The listeners get the same socket options as with `run`, and can be served later
+    /// with `run_with_listeners`, for example after dropping privileges.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Vec<std::net::TcpListener>, ServerError>` - The bound listeners, or the error
+    ///   of the first address that failed.
+    pub async fn bind(&self) -> Result<Vec<std::net::TcpListener>, ServerError> {
+        let mut std_listener_list: Vec<std::net::TcpListener> = Vec::new();
+        for tcp_listener in self.bind_addresses().await? {
+            std_listener_list.push(tcp_listener.into_std()?);
+        }
+        Ok(std_listener_list)
+    }
+
+    /// Binds the listeners of every configured address.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Vec<TcpListener>, ServerError>` - The bound listeners, or the error of the
+    ///   first address that failed.
+    async fn bind_addresses(&self) -> Result<Vec<TcpListener>, ServerError> {
+        let mut tcp_listener_list: Vec<TcpListener> = Vec::new();
+        for bind_address in self.get_server_config().get_bind_addresses() {
+            if bind_address.starts_with(UNIX_ADDRESS_PREFIX) {
+                return Err(ServerError::UnsupportedAddress(bind_address.clone()));
+            }
+            tcp_listener_list.extend(self.bind_listeners(bind_address).await?);
+        }
+        Ok(tcp_listener_list)
+    }
+
+    /// Binds the listeners for a single address.
+    ///
+    /// Each listener gets the listener socket options from the server configuration.
+    /// With `acceptors` configured on a platform supporting `SO_REUSEPORT`, several
+    /// listeners share the address and the kernel balances connections between them.
+    /// When binding port `0`, the port picked for the first listener is reused by the rest.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The address to bind to.
+    ///
+    /// # Returns
+    ///
+    /// - `io::Result<Vec<TcpListener>>` - The bound listeners.
+    async fn bind_listeners(&self, bind_address: &str) -> io::Result<Vec<TcpListener>> {
+        let mut acceptors: usize = match self.get_server_config().try_get_acceptors() {
+            Some(0) => Handle::current().metrics().num_workers(),
+            Some(acceptors) => *acceptors,
+            None => 1,
+        };
+        if !SUPPORTS_REUSEPORT {
+            acceptors = 1;
+        }
+        let reuseport: bool = acceptors > 1;
+        let mut error: io::Error = io::Error::from(ErrorKind::AddrNotAvailable);
+        for socket_address in lookup_host(bind_address).await? {
+            let tcp_listener: TcpListener = match self.bind_listener(socket_address, reuseport) {
+                Ok(tcp_listener) => tcp_listener,
+                Err(bind_error) => {
+                    error = bind_error;
+                    continue;
+                }
+            };
+            let socket_address: SocketAddr = tcp_listener.local_addr()?;
+            let mut tcp_listener_list: Vec<TcpListener> = Vec::with_capacity(acceptors);
+            tcp_listener_list.push(tcp_listener);
+            for _ in 1..acceptors {
+                tcp_listener_list.push(self.bind_listener(socket_address, reuseport)?);
+            }
+            return Ok(tcp_listener_list);
+        }
+        Err(error)
+    }
+
+    /// Enables `SO_REUSEPORT` on a listener socket.
+    ///
+    /// # Arguments
+    ///
+    /// - `&Socket` - The listener socket.
+    ///
+    /// # Returns
+    ///
+    /// - `io::Result<()>` - Whether the option was set.
+    #[cfg(reuseport)]
+    fn set_reuse_port(socket: &Socket) -> io::Result<()> {
+        socket.set_reuse_port(true)
+    }
+
+    /// Does nothing where `SUPPORTS_REUSEPORT` is `false`, since `bind_listeners`
+    /// never asks for `SO_REUSEPORT` there.
+    ///
+    /// # Returns
+    ///
+    /// - `io::Result<()>` - Always `Ok`.
+    #[cfg(not(reuseport))]
+    fn set_reuse_port(_: &Socket) -> io::Result<()> {
+        Ok(())
+    }
+
+    /// Creates a listener bound to a socket address with the configured listener options.
+    ///
+    /// IPv6 listeners only accept IPv6 connections unless `ipv6_only` is set to `false`,
+    /// so that an IPv4 and an IPv6 wildcard address can share a port.
+    ///
+    /// # Arguments
+    ///
+    /// - `SocketAddr` - The socket address to bind to.
+    /// - `bool` - Whether to enable `SO_REUSEPORT`.
+    ///
+    /// # Returns
+    ///
+    /// - `io::Result<TcpListener>` - The listening socket.
+    fn bind_listener(
+        &self,
+        socket_address: SocketAddr,
+        reuseport: bool,
+    ) -> io::Result<TcpListener> {
+        let config: &ServerConfig = self.get_server_config();
+        let socket: Socket = Socket::new(
+            Domain::for_address(socket_address),
+            Type::STREAM,
+            Some(socket2::Protocol::TCP),
+        )?;
+        socket.set_reuse_address(config.try_get_reuseaddr().unwrap_or(cfg!(unix)))?;
+        if reuseport {
+            Self::set_reuse_port(&socket)?;
+        }
+        if socket_address.is_ipv6() {
+            socket.set_only_v6(config.try_get_ipv6_only().unwrap_or(true))?;
+        }
+        if let Some(recv_buffer_size) = config.try_get_recv_buffer_size() {
+            socket.set_recv_buffer_size(*recv_buffer_size)?;
+        }
+        if let Some(send_buffer_size) = config.try_get_send_buffer_size() {
+            socket.set_send_buffer_size(*send_buffer_size)?;
+        }
+        #[cfg(any(target_os = "linux", target_os = "android"))]
+        if let Some(fastopen) = config.try_get_fastopen() {
+            use std::os::fd::AsRawFd;
+            let queue_length: libc::c_int = *fastopen as libc::c_int;
+            let result: libc::c_int = unsafe {
+                libc::setsockopt(
+                    socket.as_raw_fd(),
+                    libc::IPPROTO_TCP,
+                    libc::TCP_FASTOPEN,
+                    &queue_length as *const libc::c_int as *const libc::c_void,
+                    std::mem::size_of::<libc::c_int>() as libc::socklen_t,
+                )
+            };
+            if result != 0 {
+                return Err(io::Error::last_os_error());
+            }
+        }
+        socket.set_nonblocking(true)?;
+        socket.bind(&socket_address.into())?;
+        let backlog: u32 = config.try_get_backlog().unwrap_or(LISTEN_BACKLOG);
+        socket.listen(backlog.min(i32::MAX as u32) as i32)?;
+        TcpListener::from_std(socket.into())
+    }
+
+    /// Starts the server on an already-open listener instead of binding the configured addresses.
+    ///
+    /// This allows adopting a listener handed over by another process, for example
+    /// during a zero-downtime restart.
+    ///
+    /// # Arguments
+    ///
+    /// - `std::net::TcpListener` - The listening socket to accept connections from.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<ServerControlHook, ServerError>` - The control hook, or an error if the listener cannot be used.
+    pub async fn run_with_listener(
+        &self,
+        tcp_listener: std::net::TcpListener,
+    ) -> Result<ServerControlHook, ServerError> {
+        self.run_with_listeners(vec![tcp_listener]).await
+    }
+
+    /// Starts the server on already-open listeners instead of binding the configured addresses.
+    ///
+    /// # Arguments
+    ///
+    /// - `Vec<std::net::TcpListener>` - The listening sockets to accept connections from.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<ServerControlHook, ServerError>` - The control hook, or an error if a listener
+    ///   cannot be used. An empty list is rejected with `ServerError::TcpBind`, so a process
+    ///   started without socket activation does not run without accepting anything.
+    pub async fn run_with_listeners(
+        &self,
+        tcp_listener_list: Vec<std::net::TcpListener>,
+    ) -> Result<ServerControlHook, ServerError> {
+        if tcp_listener_list.is_empty() {
+            return Err(ServerError::TcpBind("No listener to serve".to_owned()));
+        }
+        let mut listener_list: Vec<TcpListener> = Vec::with_capacity(tcp_listener_list.len());
+        for tcp_listener in tcp_listener_list {
+            tcp_listener.set_nonblocking(true)?;
+            listener_list.push(TcpListener::from_std(tcp_listener)?);
+        }
+        Ok(self.serve(listener_list))
+    }
+
+    /// Collects the listening sockets passed by a service manager through socket activation.
+    ///
+    /// The sockets are taken from `LISTEN_FDS` starting at file descriptor `3`, and only
+    /// when `LISTEN_PID` matches the current process. The returned listeners can be
+    /// passed to `run_with_listeners`.
+    ///
+    /// The sockets are claimed at most once per process, before they are validated, so
+    /// later calls return no listener even when the claiming call failed.
+    /// The environment is left unchanged. Use `inherited_listeners_unset_environment` to
+    /// also remove the variables.
+    ///
+    /// # Returns
+    ///
+    /// - `io::Result<Vec<std::net::TcpListener>>` - The inherited listeners, empty when none
+    ///   were passed to this process, or an error if the variables are malformed or a
+    ///   descriptor is not a listening TCP socket.
+    #[cfg(unix)]
+    pub fn inherited_listeners() -> io::Result<Vec<std::net::TcpListener>> {
+        let listen_pid: Option<u32> = std::env::var(LISTEN_PID)
+            .ok()
+            .and_then(|listen_pid: String| listen_pid.parse().ok());
+        if listen_pid != Some(std::process::id()) {
+            return Ok(Vec::new());
+        }
+        let listen_fds: i32 = std::env::var(LISTEN_FDS)
+            .ok()
+            .and_then(|listen_fds: String| listen_fds.parse().ok())
+            .ok_or_else(|| {
+                io::Error::new(ErrorKind::InvalidInput, format!("Invalid {LISTEN_FDS}"))
+            })?;
+        if INHERITED_LISTENERS_CLAIMED.swap(true, std::sync::atomic::Ordering::AcqRel) {
+            return Ok(Vec::new());
+        }
+        let fd_list: std::ops::Range<i32> = LISTEN_FDS_START..LISTEN_FDS_START + listen_fds;
+        for fd in fd_list.clone() {
+            if !Self::is_tcp_listener(fd)? {
+                return Err(io::Error::new(
+                    ErrorKind::InvalidInput,
+                    format!("Inherited file descriptor {fd} is not a listening TCP socket"),
+                ));
+            }
+        }
+        let mut tcp_listener_list: Vec<std::net::TcpListener> = Vec::new();
+        for fd in fd_list {
+            unsafe {
+                libc::fcntl(fd, libc::F_SETFD, libc::FD_CLOEXEC);
+            }
+            tcp_listener_list.push(unsafe { std::os::fd::FromRawFd::from_raw_fd(fd) });
+        }
+        Ok(tcp_listener_list)
+    }
+
+    /// Collects the listening sockets passed through socket activation, then removes
+    /// `LISTEN_FDS`, `LISTEN_PID` and `LISTEN_FDNAMES` from the environment.
+    ///
+    /// This keeps the variables from being inherited by child processes. The variables
+    /// are removed even when collecting the sockets fails, like `sd_listen_fds` does.
+    ///
+    /// # Returns
+    ///
+    /// - `io::Result<Vec<std::net::TcpListener>>` - The result of `inherited_listeners`.
+    ///
+    /// # Safety
+    ///
+    /// - No other thread may read or write the environment while this runs. This is
+    ///   not the case once a multi-threaded runtime is started, including inside
+    ///   `#[tokio::main]`, so call it from `main` before building the runtime.
+    #[cfg(unix)]
+    pub unsafe fn inherited_listeners_unset_environment() -> io::Result<Vec<std::net::TcpListener>>
+    {
+        let result: io::Result<Vec<std::net::TcpListener>> = Self::inherited_listeners();
+        for name in [LISTEN_FDS, LISTEN_PID, LISTEN_FDNAMES] {
+            unsafe {
+                std::env::remove_var(name);
+            }
+        }
+        result
+    }
+
+    /// Checks whether an inherited file descriptor is a listening TCP socket.
+    ///
+    /// # Arguments
+    ///
+    /// - `i32` - The file descriptor passed by the service manager.
+    ///
+    /// # Returns
+    ///
+    /// - `io::Result<bool>` - Whether it is a listening IPv4 or IPv6 stream socket,
+    ///   or an error if it is not a socket.
+    #[cfg(unix)]
+    fn is_tcp_listener(fd: i32) -> io::Result<bool> {
+        let borrowed_fd: std::os::fd::BorrowedFd<'_> =
+            unsafe { std::os::fd::BorrowedFd::borrow_raw(fd) };
+        let socket: SockRef<'_> = SockRef::from(&borrowed_fd);
+        if socket.r#type()? != Type::STREAM || socket.local_addr()?.as_socket().is_none() {
+            return Ok(false);
+        }
+        let mut accept_connection: libc::c_int = 0;
+        let mut length: libc::socklen_t = std::mem::size_of::<libc::c_int>() as libc::socklen_t;
+        let result: libc::c_int = unsafe {
+            libc::getsockopt(
+                fd,
+                libc::SOL_SOCKET,
+                libc::SO_ACCEPTCONN,
+                &mut accept_connection as *mut libc::c_int as *mut libc::c_void,
+                &mut length,
+            )
+        };
+        if result != 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(accept_connection != 0)
+    }
+
+    /// Serves connections from the given listeners with a single route table.
+    ///
+    /// All listeners share the connection tracker and the `max_connections` limit,
+    /// and the returned control hook shuts all of them down together.
+    ///
+    /// # Arguments
+    ///
+    /// - `Vec<TcpListener>` - The listeners to accept connections from.
+    ///
+    /// # Returns
+    ///
+    /// - `ServerControlHook` - The hook to wait for and shut down the server,
+    ///   carrying the local addresses of the listeners.
+    fn serve(&self, tcp_listener_list: Vec<TcpListener>) -> ServerControlHook {
         let server: &'static Self = unsafe { self.leak() };
+        server.get_route_matcher().prepare();
+        let local_addresses: Vec<SocketAddr> = tcp_listener_list
+            .iter()
+            .filter_map(|tcp_listener: &TcpListener| tcp_listener.local_addr().ok())
+            .collect();
         let (wait_sender, wait_receiver) = channel(());
         let (shutdown_sender, mut shutdown_receiver) = channel(());
-        let accept_connections: JoinHandle<()> = spawn(async move {
-            server.tcp_accept(&tcp_listener).await;
-            let _: Result<(), tokio::sync::watch::error::SendError<()>> = wait_sender.send(());
-        });
+        let tracker: ConnectionTracker = ConnectionTracker::default();
+        let control_tracker: ConnectionTracker = tracker.clone();
+        let semaphore: Option<Arc<Semaphore>> = self
+            .get_server_config()
+            .try_get_max_connections()
+            .filter(|max_connections: &usize| *max_connections > 0)
+            .map(|max_connections: usize| Arc::new(Semaphore::new(max_connections)));
+        let accept_connections: Vec<JoinHandle<()>> = tcp_listener_list
+            .into_iter()
+            .map(|tcp_listener: TcpListener| {
+                let accept_tracker: ConnectionTracker = tracker.clone();
+                let accept_semaphore: Option<Arc<Semaphore>> = semaphore.clone();
+                let accept_shutdown_sender: Sender<()> = shutdown_sender.clone();
+                spawn(async move {
+                    server
+                        .tcp_accept(&tcp_listener, &accept_tracker, accept_semaphore)
+                        .await;
+                    let _: Result<(), tokio::sync::watch::error::SendError<()>> =
+                        accept_shutdown_sender.send(());
+                })
+            })
+            .collect();
         let wait_hook: ServerControlHookHandler<()> = Arc::new(move || {
             let mut wait_receiver_clone: Receiver<()> = wait_receiver.clone();
             Box::pin(async move {
@@ -844,11 +2143,183 @@ impl Server {
         spawn(async move {
             let _: Result<(), tokio::sync::watch::error::RecvError> =
                 shutdown_receiver.changed().await;
-            accept_connections.abort();
+            accept_connections
+                .iter()
+                .for_each(|accept_connection: &JoinHandle<()>| accept_connection.abort());
+            server.drain_connections(&tracker).await;
+            let _: Result<(), tokio::sync::watch::error::SendError<()>> = wait_sender.send(());
         });
         let mut server_control_hook: ServerControlHook = ServerControlHook::default();
         server_control_hook.set_shutdown_hook(shutdown_hook);
         server_control_hook.set_wait_hook(wait_hook);
-        Ok(server_control_hook)
+        server_control_hook.set_connection_tracker(control_tracker);
+        server_control_hook.set_local_addresses(local_addresses);
+        server_control_hook
+    }
+}
+
+/// Implementation of PROXY protocol parsing for `ProxyHeader`.
+impl ProxyHeader {
+    /// Reads a PROXY protocol v1 or v2 header from the start of a connection.
+    ///
+    /// Exactly the bytes of the header are consumed, so the rest of the stream
+    /// can be read as HTTP afterwards. A v1 header is located with a single peek,
+    /// relying on the sender writing the whole header at once as the specification requires.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut TcpStream` - The connection to read from.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<ProxyHeader, ProxyProtocolError>` - The parsed header, or why it was rejected.
+    pub async fn read(stream: &mut TcpStream) -> Result<Self, ProxyProtocolError> {
+        let mut buffer: [u8; PROXY_V1_MAX_LENGTH] = [0; PROXY_V1_MAX_LENGTH];
+        let peeked_length: usize = stream.peek(&mut buffer).await?;
+        if buffer[..peeked_length].starts_with(PROXY_V1_PREFIX) {
+            let header_length: usize = buffer[..peeked_length]
+                .windows(2)
+                .position(|window: &[u8]| window == b"\r\n")
+                .map(|position: usize| position + 2)
+                .ok_or_else(|| {
+                    ProxyProtocolError::Malformed(if peeked_length == PROXY_V1_MAX_LENGTH {
+                        "v1 header is too long".to_owned()
+                    } else {
+                        "v1 header is incomplete".to_owned()
+                    })
+                })?;
+            stream.read_exact(&mut buffer[..header_length]).await?;
+            return Self::parse_v1(&buffer[..header_length]);
+        }
+        let signature_length: usize = peeked_length.min(PROXY_V2_SIGNATURE.len());
+        if buffer[..signature_length] != PROXY_V2_SIGNATURE[..signature_length] {
+            return Err(ProxyProtocolError::Missing);
+        }
+        let mut header: [u8; 16] = [0; 16];
+        stream.read_exact(&mut header).await?;
+        if header[..12] != PROXY_V2_SIGNATURE[..] {
+            return Err(ProxyProtocolError::Missing);
+        }
+        let length: usize = u16::from_be_bytes([header[14], header[15]]) as usize;
+        let mut payload: Vec<u8> = vec![0; length];
+        stream.read_exact(&mut payload).await?;
+        Self::parse_v2(header[12], header[13], &payload)
+    }
+
+    /// Parses a PROXY protocol v1 header line.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The header line, including the trailing CRLF.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<ProxyHeader, ProxyProtocolError>` - The parsed header, or why it was rejected.
+    pub fn parse_v1(header: &[u8]) -> Result<Self, ProxyProtocolError> {
+        let malformed = || ProxyProtocolError::Malformed("invalid v1 header".to_owned());
+        let line: &str = std::str::from_utf8(header)
+            .ok()
+            .and_then(|line| line.strip_suffix("\r\n"))
+            .ok_or_else(malformed)?;
+        let parts: Vec<&str> = line.split(' ').collect();
+        match parts.as_slice() {
+            ["PROXY", "UNKNOWN", ..] => Ok(Self::default()),
+            [
+                "PROXY",
+                family @ ("TCP4" | "TCP6"),
+                source,
+                destination,
+                source_port,
+                destination_port,
+            ] => {
+                let source: IpAddr = source.parse().map_err(|_| malformed())?;
+                let destination: IpAddr = destination.parse().map_err(|_| malformed())?;
+                if source.is_ipv4() != (*family == "TCP4")
+                    || destination.is_ipv4() != source.is_ipv4()
+                {
+                    return Err(malformed());
+                }
+                let source_port: u16 = source_port.parse().map_err(|_| malformed())?;
+                let destination_port: u16 = destination_port.parse().map_err(|_| malformed())?;
+                Ok(Self {
+                    source_address: Some(SocketAddr::new(source, source_port)),
+                    destination_address: Some(SocketAddr::new(destination, destination_port)),
+                })
+            }
+            _ => Err(malformed()),
+        }
+    }
+
+    /// Parses the body of a PROXY protocol v2 header.
+    ///
+    /// Only TCP over IPv4 and IPv6 addresses are read. For any other address family,
+    /// such as `AF_UNIX`, the addresses are ignored and the connection is accepted.
+    ///
+    /// # Arguments
+    ///
+    /// - `u8` - The version and command byte.
+    /// - `u8` - The address family and transport protocol byte.
+    /// - `&[u8]` - The address block and any TLVs that follow it.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<ProxyHeader, ProxyProtocolError>` - The parsed header, or why it was rejected.
+    pub fn parse_v2(
+        version_command: u8,
+        family: u8,
+        payload: &[u8],
+    ) -> Result<Self, ProxyProtocolError> {
+        if version_command >> 4 != 2 {
+            return Err(ProxyProtocolError::Malformed(
+                "unsupported v2 version".to_owned(),
+            ));
+        }
+        match version_command & 0x0F {
+            0x00 => return Ok(Self::default()),
+            0x01 => {}
+            _ => {
+                return Err(ProxyProtocolError::Malformed(
+                    "unsupported v2 command".to_owned(),
+                ));
+            }
+        }
+        let too_short =
+            || ProxyProtocolError::Malformed("v2 address block is too short".to_owned());
+        let port = |bytes: &[u8]| u16::from_be_bytes([bytes[0], bytes[1]]);
+        match family {
+            0x11 => {
+                let block: &[u8] = payload.get(..12).ok_or_else(too_short)?;
+                let source: [u8; 4] = [block[0], block[1], block[2], block[3]];
+                let destination: [u8; 4] = [block[4], block[5], block[6], block[7]];
+                Ok(Self {
+                    source_address: Some(SocketAddr::new(
+                        Ipv4Addr::from(source).into(),
+                        port(&block[8..10]),
+                    )),
+                    destination_address: Some(SocketAddr::new(
+                        Ipv4Addr::from(destination).into(),
+                        port(&block[10..12]),
+                    )),
+                })
+            }
+            0x21 => {
+                let block: &[u8] = payload.get(..36).ok_or_else(too_short)?;
+                let mut source: [u8; 16] = [0; 16];
+                let mut destination: [u8; 16] = [0; 16];
+                source.copy_from_slice(&block[..16]);
+                destination.copy_from_slice(&block[16..32]);
+                Ok(Self {
+                    source_address: Some(SocketAddr::new(
+                        Ipv6Addr::from(source).into(),
+                        port(&block[32..34]),
+                    )),
+                    destination_address: Some(SocketAddr::new(
+                        Ipv6Addr::from(destination).into(),
+                        port(&block[34..36]),
+                    )),
+                })
+            }
+            _ => Ok(Self::default()),
+        }
     }
 }
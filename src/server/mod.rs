@@ -1,6 +1,10 @@
+mod r#const;
 mod r#impl;
+mod r#static;
 mod r#struct;
 
-pub use r#struct::*;
+pub use {r#const::*, r#struct::*};
+
+pub(crate) use r#static::*;
 
 use super::*;
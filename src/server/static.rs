@@ -0,0 +1,4 @@
+/// Whether the sockets passed through socket activation were already claimed by this process.
+#[cfg(unix)]
+pub(crate) static INHERITED_LISTENERS_CLAIMED: std::sync::atomic::AtomicBool =
+    std::sync::atomic::AtomicBool::new(false);
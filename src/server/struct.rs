@@ -25,8 +25,46 @@ pub struct Server {
     #[debug(skip)]
     #[set(skip)]
     pub(super) request_middleware: ServerHookList,
+    /// A collection of handlers invoked when no route matches the request path.
+    #[debug(skip)]
+    #[set(skip)]
+    pub(super) not_found: ServerHookList,
+    /// A collection of handlers invoked when a path matches a route but not the request method.
+    #[debug(skip)]
+    #[set(skip)]
+    pub(super) method_not_allowed: ServerHookList,
     /// A collection of response middleware handlers.
     #[debug(skip)]
     #[set(skip)]
     pub(super) response_middleware: ServerHookList,
+    /// A collection of handlers invoked when accepting a connection fails.
+    #[debug(skip)]
+    #[set(skip)]
+    pub(super) accept_error: AcceptErrorHookList,
+}
+
+/// The addresses announced by a PROXY protocol header.
+///
+/// Both addresses are `None` for headers that do not carry TCP addresses,
+/// such as v1 `UNKNOWN` or v2 `LOCAL` headers.
+#[derive(Clone, Copy, Data, Debug, Default, DisplayDebug, Eq, PartialEq)]
+pub struct ProxyHeader {
+    /// The original client address.
+    pub(super) source_address: Option<SocketAddr>,
+    /// The original destination address.
+    pub(super) destination_address: Option<SocketAddr>,
+}
+
+/// Tracks the connections served by a running server.
+///
+/// It counts the active connections and broadcasts the shutdown phases, so that
+/// connections can finish their current response before the server stops.
+#[derive(Clone, Debug, DisplayDebug)]
+pub struct ConnectionTracker {
+    /// The number of connections currently being served.
+    pub(super) connection_count: Sender<usize>,
+    /// Set once the server stops accepting and connections should close after their current response.
+    pub(super) shutting_down: Sender<bool>,
+    /// Set once the drain deadline elapses and remaining connections must close immediately.
+    pub(super) force_close: Sender<bool>,
 }
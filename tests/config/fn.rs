@@ -17,3 +17,68 @@ fn server_config_from_json() {
         .set_ttl(Some(64));
     assert_eq!(server_config, new_server_config);
 }
+
+#[test]
+fn server_config_additional_addresses_from_json() {
+    let server_config_json: &'static str = r#"
+    {
+        "address": "0.0.0.0:80",
+        "additional_addresses": ["[::]:80", "127.0.0.1:8080"],
+        "nodelay": true,
+        "ttl": 64
+    }
+    "#;
+    let server_config: ServerConfig = ServerConfig::from_json(server_config_json).unwrap();
+    let mut new_server_config: ServerConfig = ServerConfig::default();
+    new_server_config
+        .set_address("0.0.0.0:80")
+        .add_address("[::]:80")
+        .add_address("127.0.0.1:8080")
+        .set_nodelay(Some(true))
+        .set_ttl(Some(64));
+    assert_eq!(server_config, new_server_config);
+    assert_eq!(
+        server_config.get_bind_addresses(),
+        vec!["0.0.0.0:80", "[::]:80", "127.0.0.1:8080"]
+    );
+}
+
+#[test]
+fn server_config_socket_options_from_json() {
+    let server_config_json: &'static str = r#"
+    {
+        "address": "0.0.0.0:80",
+        "nodelay": true,
+        "ttl": 64,
+        "backlog": 2048,
+        "reuseaddr": true,
+        "ipv6_only": false,
+        "fastopen": 16,
+        "keepalive_idle_ms": 60000,
+        "keepalive_interval_ms": 10000,
+        "keepalive_retries": 5,
+        "recv_buffer_size": 65536,
+        "send_buffer_size": 65536,
+        "linger_secs": 0
+    }
+    "#;
+    let server_config: ServerConfig = ServerConfig::from_json(server_config_json).unwrap();
+    let mut new_server_config: ServerConfig = ServerConfig::default();
+    new_server_config
+        .set_address("0.0.0.0:80")
+        .set_nodelay(Some(true))
+        .set_ttl(Some(64))
+        .set_backlog(Some(2048))
+        .set_reuseaddr(Some(true))
+        .set_ipv6_only(Some(false))
+        .set_fastopen(Some(16))
+        .set_keepalive_idle_ms(Some(60000))
+        .set_keepalive_interval_ms(Some(10000))
+        .set_keepalive_retries(Some(5))
+        .set_recv_buffer_size(Some(65536))
+        .set_send_buffer_size(Some(65536))
+        .set_linger_secs(Some(0));
+    assert_eq!(server_config, new_server_config);
+    assert!(server_config.try_get_tcp_keepalive().is_some());
+    assert!(ServerConfig::default().try_get_tcp_keepalive().is_none());
+}
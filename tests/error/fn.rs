@@ -29,3 +29,49 @@ fn route_error() {
         RouteError::InvalidRegexPattern("[".to_string());
     assert_eq!(invalid_regex_pattern_error, new_invalid_regex_pattern_error);
 }
+
+#[test]
+fn connection_timeout() {
+    let keep_alive: ConnectionTimeout = ConnectionTimeout::KeepAlive;
+    assert_eq!(keep_alive, ConnectionTimeout::KeepAlive);
+    assert_ne!(keep_alive, ConnectionTimeout::RequestHeader);
+    assert_ne!(keep_alive, ConnectionTimeout::RequestRead);
+    let ctx: Context = Context::default();
+    assert!(ctx.try_get_connection_timeout().is_none());
+    assert_eq!(ConnectionTimeout::RequestRead.to_string(), "RequestRead");
+}
+
+#[test]
+fn accept_error() {
+    let aborted: std::io::Error = std::io::Error::from(std::io::ErrorKind::ConnectionAborted);
+    assert!(matches!(
+        AcceptError::from(&aborted),
+        AcceptError::Transient(_)
+    ));
+    let out_of_memory: std::io::Error = std::io::Error::from(std::io::ErrorKind::OutOfMemory);
+    assert!(matches!(
+        AcceptError::from(&out_of_memory),
+        AcceptError::ResourceExhausted(_)
+    ));
+    let invalid_input: std::io::Error = std::io::Error::from(std::io::ErrorKind::InvalidInput);
+    assert!(matches!(
+        AcceptError::from(&invalid_input),
+        AcceptError::Fatal(_)
+    ));
+    #[cfg(unix)]
+    {
+        let too_many_open_files: std::io::Error = std::io::Error::from_raw_os_error(24);
+        assert!(matches!(
+            AcceptError::from(&too_many_open_files),
+            AcceptError::ResourceExhausted(_)
+        ));
+    }
+    #[cfg(windows)]
+    for raw_os_error in [WSAEMFILE, WSAENOBUFS] {
+        let resource_exhausted: std::io::Error = std::io::Error::from_raw_os_error(raw_os_error);
+        assert!(matches!(
+            AcceptError::from(&resource_exhausted),
+            AcceptError::ResourceExhausted(_)
+        ));
+    }
+}
@@ -6,9 +6,19 @@ mod server;
 
 use hyperlane::*;
 
+use socket2::SockRef;
+
 use std::{
-    sync::{Arc, OnceLock},
+    io::ErrorKind,
+    net::SocketAddr,
+    sync::{Arc, Mutex, OnceLock},
     time::{Duration, Instant},
 };
 
-use tokio::{spawn, task::JoinHandle, time::sleep};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpStream,
+    spawn,
+    task::JoinHandle,
+    time::{sleep, timeout},
+};
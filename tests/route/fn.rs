@@ -14,7 +14,145 @@ async fn duplicate_route() {
         .route::<TestRoute>(ROOT_PATH);
 }
 
+#[tokio::test]
+#[should_panic(expected = "DuplicatePattern")]
+async fn duplicate_method_route() {
+    let _server: &Server = Server::default()
+        .route_method::<TestRoute>(Method::Post, "/users")
+        .route_method::<TestRoute>(Method::Post, "/users");
+}
+
+#[tokio::test]
+#[should_panic(expected = "InvalidCatchAllPattern")]
+async fn catch_all_not_last_route() {
+    let _server: &Server = Server::default().route::<TestRoute>("/files/{*path}/raw");
+}
+
+#[tokio::test]
+#[should_panic(expected = "InvalidCatchAllPattern")]
+async fn unnamed_catch_all_route() {
+    let _server: &Server = Server::default().route::<TestRoute>("/files/{*}");
+}
+
+#[tokio::test]
+#[should_panic(expected = "InvalidCatchAllPattern")]
+async fn regex_catch_all_route() {
+    let _server: &Server = Server::default().route::<TestRoute>("/files/{*path:.*\\.txt}");
+}
+
+#[test]
+fn method_route() {
+    let mut server: Server = Server::default();
+    server
+        .route_method::<TestRoute>(Method::Get, "/users")
+        .route_method::<TestRoute>(Method::Post, "/users")
+        .route_method::<TestRoute>(Method::Delete, "/users/{id}")
+        .route_method::<TestRoute>(Method::Put, "/files/{id:[0-9]+}");
+    let route_matcher: RouteMatcher = server.get_route_matcher().clone();
+    assert_eq!(route_matcher.get_static_route().len(), 1);
+    let mut ctx: Context = Context::default();
+    ctx.get_mut_request().set_method(Method::Get);
+    assert!(
+        route_matcher
+            .try_resolve_route(&mut ctx, "/users")
+            .is_some()
+    );
+    assert!(
+        route_matcher
+            .try_resolve_route(&mut ctx, "/users/1")
+            .is_none()
+    );
+    assert!(
+        route_matcher
+            .try_resolve_route(&mut ctx, "/files/1")
+            .is_none()
+    );
+    ctx.get_mut_request().set_method(Method::Post);
+    assert!(
+        route_matcher
+            .try_resolve_route(&mut ctx, "/users")
+            .is_some()
+    );
+    ctx.get_mut_request().set_method(Method::Delete);
+    assert!(
+        route_matcher
+            .try_resolve_route(&mut ctx, "/users")
+            .is_none()
+    );
+    assert!(
+        route_matcher
+            .try_resolve_route(&mut ctx, "/users/1")
+            .is_some()
+    );
+    assert_eq!(ctx.try_get_route_param("id"), Some("1".to_string()));
+    ctx.get_mut_request().set_method(Method::Put);
+    assert!(
+        route_matcher
+            .try_resolve_route(&mut ctx, "/files/1")
+            .is_some()
+    );
+    assert!(
+        route_matcher
+            .try_resolve_route(&mut ctx, "/files/abc")
+            .is_none()
+    );
+}
+
+#[test]
+#[allow(deprecated)]
+fn method_route_with_any_method_route() {
+    let mut server: Server = Server::default();
+    server
+        .route::<TestRoute>("/users/{id}")
+        .route_method::<TestRoute>(Method::Delete, "/users/{id}");
+    let route_matcher: RouteMatcher = server.get_route_matcher().clone();
+    assert_eq!(route_matcher.get_dynamic_route().get(&2).unwrap().len(), 1);
+    let route_list: RouteGroupList = route_matcher.get_route_list();
+    assert_eq!(route_list.len(), 2);
+    assert!(
+        route_list.iter().all(
+            |(_, path, _): &(Option<Method>, String, ServerHookHandler)| path == "/users/{id}"
+        )
+    );
+    let mut ctx: Context = Context::default();
+    for method in [Method::Get, Method::Delete, Method::Patch] {
+        ctx.get_mut_request().set_method(method);
+        assert!(
+            route_matcher
+                .try_resolve_route(&mut ctx, "/users/1")
+                .is_some()
+        );
+    }
+}
+
 #[test]
+fn allowed_methods() {
+    let mut server: Server = Server::default();
+    server
+        .route_method::<TestRoute>(Method::Get, "/users")
+        .route_method::<TestRoute>(Method::Post, "/users")
+        .route_method::<TestRoute>(Method::Delete, "/users/{id}")
+        .route_method::<TestRoute>(Method::Put, "/users/{id:[0-9]+}")
+        .route::<TestRoute>("/about");
+    let route_matcher: RouteMatcher = server.get_route_matcher().clone();
+    assert_eq!(
+        route_matcher.get_allowed_methods("/users"),
+        vec![Method::Get, Method::Post]
+    );
+    assert_eq!(
+        route_matcher.get_allowed_methods("/users/1"),
+        vec![Method::Delete, Method::Put]
+    );
+    assert_eq!(
+        route_matcher.get_allowed_methods("/users/abc"),
+        vec![Method::Delete]
+    );
+    assert!(route_matcher.get_allowed_methods("/about").is_empty());
+    assert!(route_matcher.get_allowed_methods("/missing").is_empty());
+}
+
+#[test]
+#[allow(deprecated)]
 fn get_route() {
     let mut server: Server = Server::default();
     server
@@ -35,9 +173,13 @@ fn get_route() {
             println!("Regex route: {route_pattern}");
         }
     }
+    for (_, path, _) in route_matcher.get_route_list() {
+        println!("Route: {path}");
+    }
 }
 
 #[test]
+#[allow(deprecated)]
 fn segment_count_optimization() {
     let mut server: Server = Server::default();
     server.route::<TestRoute>("/users/{id}");
@@ -45,6 +187,7 @@ fn segment_count_optimization() {
     server.route::<TestRoute>("/users/{id}/posts/{post_id}");
     server.route::<TestRoute>("/api/v1/users/{id}");
     let route_matcher: RouteMatcher = server.get_route_matcher().clone();
+    assert_eq!(route_matcher.get_route_list().len(), 4);
     assert!(
         route_matcher.get_dynamic_route().contains_key(&2),
         "Should have 2-segment routes"
@@ -60,15 +203,35 @@ fn segment_count_optimization() {
     assert_eq!(route_matcher.get_dynamic_route().get(&2).unwrap().len(), 1);
     assert_eq!(route_matcher.get_dynamic_route().get(&3).unwrap().len(), 1);
     assert_eq!(route_matcher.get_dynamic_route().get(&4).unwrap().len(), 2);
+    let mut ctx: Context = Context::default();
+    for path in [
+        "/users/1",
+        "/users/1/posts",
+        "/users/1/posts/2",
+        "/api/v1/users/1",
+    ] {
+        assert!(
+            route_matcher.try_resolve_route(&mut ctx, path).is_some(),
+            "Should match {path}"
+        );
+    }
+    for path in ["/users", "/users/1/comments", "/users/1/posts/2/edit"] {
+        assert!(
+            route_matcher.try_resolve_route(&mut ctx, path).is_none(),
+            "Should not match {path}"
+        );
+    }
 }
 
 #[test]
+#[allow(deprecated)]
 fn regex_route_segment_count() {
     let mut server: Server = Server::default();
     server.route::<TestRoute>("/files/{path:.*}");
     server.route::<TestRoute>("/api/{version:\\d+}/users");
     server.route::<TestRoute>("/api/{version:\\d+}/posts/{id:\\d+}");
     let route_matcher: RouteMatcher = server.get_route_matcher().clone();
+    assert_eq!(route_matcher.get_route_list().len(), 3);
     assert!(
         route_matcher.get_regex_route().contains_key(&2),
         "Should have 2-segment regex routes"
@@ -81,9 +244,24 @@ fn regex_route_segment_count() {
         route_matcher.get_regex_route().contains_key(&4),
         "Should have 4-segment regex routes"
     );
+    assert!(route_matcher.get_dynamic_route().is_empty());
+    let mut ctx: Context = Context::default();
+    for path in ["/files/a/b/c", "/api/1/users", "/api/1/posts/2"] {
+        assert!(
+            route_matcher.try_resolve_route(&mut ctx, path).is_some(),
+            "Should match {path}"
+        );
+    }
+    for path in ["/api/v1/users", "/api/1/posts/abc"] {
+        assert!(
+            route_matcher.try_resolve_route(&mut ctx, path).is_none(),
+            "Should not match {path}"
+        );
+    }
 }
 
 #[test]
+#[allow(deprecated)]
 fn mixed_route_types() {
     let mut server: Server = Server::default();
     server.route::<TestRoute>("/");
@@ -95,9 +273,172 @@ fn mixed_route_types() {
     assert_eq!(route_matcher.get_static_route().len(), 2);
     assert!(route_matcher.get_dynamic_route().contains_key(&2));
     assert!(route_matcher.get_regex_route().contains_key(&2));
+    assert_eq!(route_matcher.get_route_list().len(), 5);
+}
+
+#[test]
+fn route_precedence() {
+    let mut server: Server = Server::default();
+    server
+        .route::<TestRoute>("/posts/{id}/edit")
+        .route::<TestRoute>("/posts/new/{action}")
+        .route::<TestRoute>("/numbers/{number:[0-9]+}")
+        .route::<TestRoute>("/numbers/{name}")
+        .route::<TestRoute>("/files/{name:[a-z]+}")
+        .route::<TestRoute>("/files/{path:.*}")
+        .route::<TestRoute>("/users/{id}/posts")
+        .route::<TestRoute>("/users/me/{tab}/settings");
+    let route_matcher: RouteMatcher = server.get_route_matcher().clone();
+    let mut ctx: Context = Context::default();
+    assert!(
+        route_matcher
+            .try_resolve_route(&mut ctx, "/posts/new/edit")
+            .is_some()
+    );
+    assert_eq!(ctx.try_get_route_param("action"), Some("edit".to_owned()));
+    assert_eq!(ctx.try_get_route_param("id"), None);
+    assert!(
+        route_matcher
+            .try_resolve_route(&mut ctx, "/numbers/42")
+            .is_some()
+    );
+    assert_eq!(ctx.try_get_route_param("name"), Some("42".to_owned()));
+    assert!(
+        route_matcher
+            .try_resolve_route(&mut ctx, "/files/readme")
+            .is_some()
+    );
+    assert_eq!(ctx.try_get_route_param("name"), Some("readme".to_owned()));
+    assert!(
+        route_matcher
+            .try_resolve_route(&mut ctx, "/files/docs/readme.md")
+            .is_some()
+    );
+    assert_eq!(
+        ctx.try_get_route_param("path"),
+        Some("docs/readme.md".to_owned())
+    );
+    assert!(
+        route_matcher
+            .try_resolve_route(&mut ctx, "/users/me/posts")
+            .is_some()
+    );
+    assert_eq!(ctx.try_get_route_param("id"), Some("me".to_owned()));
+    assert!(
+        route_matcher
+            .try_resolve_route(&mut ctx, "/users/me/posts/other")
+            .is_none()
+    );
 }
 
 #[test]
+fn sibling_regex_routes() {
+    let mut server: Server = Server::default();
+    server
+        .route::<TestRoute>("/items/{number:[0-9]+}")
+        .route::<TestRoute>("/items/{word:[a-z]+}")
+        .route::<TestRoute>("/items/{choice:a|ab}")
+        .route::<TestRoute>("/items/{rest:x.*}");
+    let route_matcher: RouteMatcher = server.get_route_matcher().clone();
+    let mut ctx: Context = Context::default();
+    assert!(
+        route_matcher
+            .try_resolve_route(&mut ctx, "/items/42")
+            .is_some()
+    );
+    assert_eq!(ctx.try_get_route_param("number"), Some("42".to_owned()));
+    assert!(
+        route_matcher
+            .try_resolve_route(&mut ctx, "/items/ab")
+            .is_some()
+    );
+    assert_eq!(ctx.try_get_route_param("choice"), Some("ab".to_owned()));
+    assert!(
+        route_matcher
+            .try_resolve_route(&mut ctx, "/items/abc")
+            .is_some()
+    );
+    assert_eq!(ctx.try_get_route_param("word"), Some("abc".to_owned()));
+    assert!(
+        route_matcher
+            .try_resolve_route(&mut ctx, "/items/x/1/2")
+            .is_some()
+    );
+    assert_eq!(ctx.try_get_route_param("rest"), Some("x/1/2".to_owned()));
+    assert!(
+        route_matcher
+            .try_resolve_route(&mut ctx, "/items/A1")
+            .is_none()
+    );
+}
+
+#[test]
+fn catch_all_route() {
+    let mut server: Server = Server::default();
+    server
+        .route::<TestRoute>("/static/{*path}")
+        .route::<TestRoute>("/static/special/{id}")
+        .route::<TestRoute>("/mixed/{file:.*\\.txt}")
+        .route::<TestRoute>("/mixed/{*rest}");
+    let route_matcher: RouteMatcher = server.get_route_matcher().clone();
+    assert!(route_matcher.get_route_list().iter().any(
+        |(_, path, _): &(Option<Method>, String, ServerHookHandler)| path == "/mixed/{*rest}"
+    ));
+    let mut ctx: Context = Context::default();
+    assert!(
+        route_matcher
+            .try_resolve_route(&mut ctx, "/static/css/site/main.css")
+            .is_some()
+    );
+    assert_eq!(
+        ctx.try_get_route_param("path"),
+        Some("css/site/main.css".to_owned())
+    );
+    assert!(
+        route_matcher
+            .try_resolve_route(&mut ctx, "/static")
+            .is_some()
+    );
+    assert_eq!(ctx.try_get_route_param("path"), Some(String::new()));
+    assert!(
+        route_matcher
+            .try_resolve_route(&mut ctx, "/static/special/1")
+            .is_some()
+    );
+    assert_eq!(ctx.try_get_route_param("id"), Some("1".to_owned()));
+    assert!(
+        route_matcher
+            .try_resolve_route(&mut ctx, "/static/special")
+            .is_some()
+    );
+    assert_eq!(ctx.try_get_route_param("path"), Some("special".to_owned()));
+    assert!(
+        route_matcher
+            .try_resolve_route(&mut ctx, "/mixed/docs/notes.txt")
+            .is_some()
+    );
+    assert_eq!(
+        ctx.try_get_route_param("file"),
+        Some("docs/notes.txt".to_owned())
+    );
+    assert!(
+        route_matcher
+            .try_resolve_route(&mut ctx, "/mixed/docs/image.png")
+            .is_some()
+    );
+    assert_eq!(
+        ctx.try_get_route_param("rest"),
+        Some("docs/image.png".to_owned())
+    );
+    assert!(
+        route_matcher
+            .try_resolve_route(&mut ctx, "/other")
+            .is_none()
+    );
+}
+
+#[test]
+#[allow(deprecated)]
 fn large_dynamic_routes() {
     const ROUTE_COUNT: u32 = 1000;
     let mut server: Server = Server::default();
@@ -113,6 +454,7 @@ fn large_dynamic_routes() {
     );
     let route_matcher: RouteMatcher = server.get_route_matcher().clone();
     assert!(!route_matcher.get_dynamic_route().is_empty());
+    assert_eq!(route_matcher.get_route_list().len(), ROUTE_COUNT as usize);
     let mut ctx: Context = Context::default();
     let start_match: Instant = Instant::now();
     for i in 0..ROUTE_COUNT {
@@ -131,6 +473,7 @@ fn large_dynamic_routes() {
 }
 
 #[test]
+#[allow(deprecated)]
 fn large_regex_routes() {
     const ROUTE_COUNT: u32 = 1000;
     let mut server: Server = Server::default();
@@ -146,6 +489,7 @@ fn large_regex_routes() {
     );
     let route_matcher: RouteMatcher = server.get_route_matcher().clone();
     assert!(!route_matcher.get_regex_route().is_empty());
+    assert_eq!(route_matcher.get_route_list().len(), ROUTE_COUNT as usize);
     let mut ctx: Context = Context::default();
     let start_match: Instant = Instant::now();
     for i in 0..ROUTE_COUNT {
@@ -164,6 +508,7 @@ fn large_regex_routes() {
 }
 
 #[test]
+#[allow(deprecated)]
 fn large_tail_regex_routes() {
     const ROUTE_COUNT: u32 = 1000;
     let mut server: Server = Server::default();
@@ -179,6 +524,7 @@ fn large_tail_regex_routes() {
     );
     let route_matcher: RouteMatcher = server.get_route_matcher().clone();
     assert!(!route_matcher.get_regex_route().is_empty());
+    assert_eq!(route_matcher.get_route_list().len(), ROUTE_COUNT as usize);
     let mut ctx: Context = Context::default();
     let start_match: Instant = Instant::now();
     for i in 0..ROUTE_COUNT {
@@ -195,3 +541,80 @@ fn large_tail_regex_routes() {
         match_duration / ROUTE_COUNT
     );
 }
+
+#[test]
+fn large_sibling_regex_routes() {
+    const ROUTE_COUNT: u32 = 1000;
+    let mut server: Server = Server::default();
+    let start_insert: Instant = Instant::now();
+    for i in 0..ROUTE_COUNT {
+        let path: String = format!("/api/{{id{i}:item{i}-[0-9]+}}");
+        server.route::<TestRoute>(&path);
+    }
+    let insert_duration: Duration = start_insert.elapsed();
+    println!(
+        "Inserted {} sibling regex routes in: {:?}",
+        ROUTE_COUNT, insert_duration
+    );
+    let route_matcher: RouteMatcher = server.get_route_matcher().clone();
+    route_matcher.prepare();
+    let mut ctx: Context = Context::default();
+    let start_match: Instant = Instant::now();
+    for i in 0..ROUTE_COUNT {
+        let path: String = format!("/api/item{i}-123");
+        let _: Option<&ServerHookHandler> = route_matcher.try_resolve_route(&mut ctx, &path);
+    }
+    let match_duration: Duration = start_match.elapsed();
+    println!(
+        "Matched {} sibling regex routes in: {:?}",
+        ROUTE_COUNT, match_duration
+    );
+    println!(
+        "Average per sibling regex route match: {:?}",
+        match_duration / ROUTE_COUNT
+    );
+}
+
+#[test]
+fn route_group_join_path() {
+    assert_eq!(RouteGroup::join_path("/api", "/users"), "/api/users");
+    assert_eq!(RouteGroup::join_path("/api/", "users"), "/api/users");
+    assert_eq!(RouteGroup::join_path("/api", "/"), "/api");
+    assert_eq!(RouteGroup::join_path("/", "/users/{id}"), "/users/{id}");
+    assert_eq!(RouteGroup::join_path("", ""), "/");
+}
+
+#[test]
+fn route_group_is_under_prefix() {
+    assert!(RouteGroup::is_under_prefix("/api", "/api"));
+    assert!(RouteGroup::is_under_prefix("/api/", "/api/users"));
+    assert!(!RouteGroup::is_under_prefix("/api", "/apis"));
+    assert!(!RouteGroup::is_under_prefix("/api", "/"));
+    assert!(RouteGroup::is_under_prefix("/", "/anything"));
+}
+
+#[test]
+fn oversized_sibling_regex_routes() {
+    let mut server: Server = Server::default();
+    for i in 0..16 {
+        let path: String = format!("/api/{{id{i}:\\w{{150}}{i}}}");
+        server.route::<TestRoute>(&path);
+    }
+    let route_matcher: RouteMatcher = server.get_route_matcher().clone();
+    route_matcher.prepare();
+    let mut ctx: Context = Context::default();
+    for i in 0..16 {
+        let value: String = format!("{}{i}", "a".repeat(150));
+        assert!(
+            route_matcher
+                .try_resolve_route(&mut ctx, &format!("/api/{value}"))
+                .is_some()
+        );
+        assert_eq!(ctx.try_get_route_param(format!("id{i}")), Some(value));
+    }
+    assert!(
+        route_matcher
+            .try_resolve_route(&mut ctx, "/api/1")
+            .is_none()
+    );
+}
@@ -1,5 +1,34 @@
 use super::*;
 
+async fn send_http_request(address: SocketAddr, request: &str) -> String {
+    let mut stream: TcpStream = TcpStream::connect(address).await.unwrap();
+    stream.write_all(request.as_bytes()).await.unwrap();
+    let mut response: Vec<u8> = Vec::new();
+    stream.read_to_end(&mut response).await.unwrap();
+    String::from_utf8_lossy(&response).to_lowercase()
+}
+
+async fn run_server(
+    server: &mut Server,
+    mut server_config: ServerConfig,
+) -> (ServerControlHook, SocketAddr) {
+    server_config.set_address("127.0.0.1:0");
+    server.server_config(server_config);
+    let server_control_hook: ServerControlHook = server.run().await.unwrap();
+    let address: SocketAddr = server_control_hook.get_local_address();
+    (server_control_hook, address)
+}
+
+async fn is_connection_rejected(address: SocketAddr, request: &str) -> bool {
+    let mut stream: TcpStream = TcpStream::connect(address).await.unwrap();
+    stream.write_all(request.as_bytes()).await.unwrap();
+    let mut response: Vec<u8> = Vec::new();
+    match stream.read_to_end(&mut response).await {
+        Ok(_) => response.is_empty(),
+        Err(_) => true,
+    }
+}
+
 #[test]
 fn server_partial_eq() {
     let server1: Server = Server::default();
@@ -151,12 +180,725 @@ async fn server_share_across_threads() {
     assert_eq!(result2, "thread2");
 }
 
+#[tokio::test]
+async fn method_not_allowed() {
+    let mut server: Server = Server::default();
+    server.request_middleware::<RequestMiddleware>();
+    server.response_middleware::<ResponseMiddleware>();
+    server.route_method::<TestSendRoute>(Method::Get, "/users");
+    server.route_method::<TestSendRoute>(Method::Post, "/users");
+    let (server_control_hook, address): (ServerControlHook, SocketAddr) =
+        run_server(&mut server, ServerConfig::default()).await;
+    let response: String = send_http_request(
+        address,
+        "DELETE /users HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n",
+    )
+    .await;
+    assert!(response.starts_with("http/1.1 405 method not allowed"));
+    assert!(response.contains("allow:get,post"));
+    let response: String = send_http_request(
+        address,
+        "POST /users HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n",
+    )
+    .await;
+    assert!(response.starts_with("http/1.1 200"));
+    server_control_hook.shutdown().await;
+    server_control_hook.wait().await;
+}
+
+#[tokio::test]
+async fn not_found() {
+    let mut server: Server = Server::default();
+    server.request_middleware::<RequestMiddleware>();
+    server.response_middleware::<ResponseMiddleware>();
+    server.route::<TestSendRoute>("/exists");
+    let (server_control_hook, address): (ServerControlHook, SocketAddr) =
+        run_server(&mut server, ServerConfig::default()).await;
+    let response: String = send_http_request(
+        address,
+        "GET /missing HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n",
+    )
+    .await;
+    assert!(response.starts_with("http/1.1 404 not found"));
+    server_control_hook.shutdown().await;
+    server_control_hook.wait().await;
+}
+
+#[tokio::test]
+async fn not_found_hook() {
+    let mut server: Server = Server::default();
+    server.request_middleware::<RequestMiddleware>();
+    server.response_middleware::<ResponseMiddleware>();
+    server.not_found::<NotFoundHook>();
+    server.route::<TestSendRoute>("/exists");
+    let (server_control_hook, address): (ServerControlHook, SocketAddr) =
+        run_server(&mut server, ServerConfig::default()).await;
+    let response: String = send_http_request(
+        address,
+        "GET /missing HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n",
+    )
+    .await;
+    assert!(response.starts_with("http/1.1 404 not found"));
+    assert!(response.ends_with("no route for /missing"));
+    let response: String = send_http_request(
+        address,
+        "GET /exists HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n",
+    )
+    .await;
+    assert!(response.starts_with("http/1.1 200"));
+    server_control_hook.shutdown().await;
+    server_control_hook.wait().await;
+}
+
+#[test]
+fn hook_order() {
+    let mut server: Server = Server::default();
+    server.handle_hook(HookType::RequestMiddleware(
+        Some(-1),
+        Hook::factory::<LowOrderMiddleware>,
+    ));
+    server.handle_hook(HookType::RequestMiddleware(
+        None,
+        Hook::factory::<TestSendRoute>,
+    ));
+    server.handle_hook(HookType::RequestMiddleware(
+        Some(10),
+        Hook::factory::<HighOrderMiddleware>,
+    ));
+    server.request_middleware::<TestSendRoute>();
+    server.request_middleware_with_order::<HighOrderMiddleware>(10);
+    let order_list: Vec<isize> = server
+        .get_request_middleware()
+        .iter()
+        .map(|(order, _)| *order)
+        .collect();
+    assert_eq!(order_list, vec![10, 10, 0, 0, -1]);
+}
+
+#[test]
+#[should_panic(expected = "Unknown HTTP method get for route /lowercase")]
+fn route_method_hook_unknown_method() {
+    let mut server: Server = Server::default();
+    server.handle_hook(HookType::RouteMethod(
+        "get",
+        "/lowercase",
+        Hook::factory::<TestSendRoute>,
+    ));
+}
+
+#[test]
+fn server_from_inventory() {
+    let server: Server = Server::from_inventory();
+    assert!(
+        server
+            .get_route_matcher()
+            .get_static_route()
+            .contains_key("/inventory")
+    );
+    let order_list: Vec<isize> = server
+        .get_request_middleware()
+        .iter()
+        .map(|(order, _)| *order)
+        .collect();
+    assert_eq!(order_list, vec![10, -1]);
+}
+
+#[tokio::test]
+async fn accept_error_hook() {
+    let mut server: Server = Server::default();
+    server.handle_hook(HookType::AcceptError(
+        Some(1),
+        Hook::accept_error_factory::<AcceptErrorLogger>,
+    ));
+    server.accept_error::<AcceptErrorLogger>();
+    let order_list: Vec<isize> = server
+        .get_accept_error()
+        .iter()
+        .map(|(order, _)| *order)
+        .collect();
+    assert_eq!(order_list, vec![1, 0]);
+    let error: AcceptError = AcceptError::Transient("connection aborted".to_string());
+    for (_, hook) in server.get_accept_error().iter() {
+        assert!(hook(&error).await.is_continue());
+    }
+}
+
+#[tokio::test]
+async fn hook_order_execution() {
+    let mut server: Server = Server::default();
+    server.request_middleware_with_order::<LowOrderMiddleware>(-1);
+    server.request_middleware_with_order::<HighOrderMiddleware>(10);
+    server.response_middleware::<ResponseMiddleware>();
+    server.route::<TestSendRoute>("/");
+    let (server_control_hook, address): (ServerControlHook, SocketAddr) =
+        run_server(&mut server, ServerConfig::default()).await;
+    let response: String = send_http_request(
+        address,
+        "GET / HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n",
+    )
+    .await;
+    assert!(response.ends_with("high,low,"));
+    server_control_hook.shutdown().await;
+    server_control_hook.wait().await;
+}
+
+#[tokio::test]
+async fn route_group() {
+    let mut server: Server = Server::default();
+    server.response_middleware::<ResponseMiddleware>();
+    server.route::<TestSendRoute>("/plain");
+    server.group("/api", |group: &mut RouteGroup| {
+        group.request_middleware::<HighOrderMiddleware>();
+        group.route::<TestSendRoute>("/");
+        group.group("/v1", |group: &mut RouteGroup| {
+            group.response_middleware::<LowOrderMiddleware>();
+            group.route_method::<TestSendRoute>(Method::Get, "/users");
+        });
+    });
+    let (server_control_hook, address): (ServerControlHook, SocketAddr) =
+        run_server(&mut server, ServerConfig::default()).await;
+    let response: String = send_http_request(
+        address,
+        "GET /api/v1/users HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n",
+    )
+    .await;
+    assert!(response.ends_with("high,low,"));
+    let response: String = send_http_request(
+        address,
+        "GET /api HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n",
+    )
+    .await;
+    assert!(response.ends_with("high,"));
+    let response: String = send_http_request(
+        address,
+        "GET /plain HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n",
+    )
+    .await;
+    assert!(response.starts_with("http/1.1 200"));
+    assert!(!response.contains("high,"));
+    let response: String = send_http_request(
+        address,
+        "POST /api/v1/users HTTP/1.1\r\nHost: localhost\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+    )
+    .await;
+    assert!(response.starts_with("http/1.1 405"));
+    server_control_hook.shutdown().await;
+    server_control_hook.wait().await;
+}
+
+#[tokio::test]
+async fn mount_server() {
+    let mut module: Server = Server::default();
+    module.request_middleware::<HighOrderMiddleware>();
+    module.route::<TestSendRoute>("/users");
+    module.route_method::<TestSendRoute>(Method::Get, "/items/{id}");
+    module.route::<TestSendRoute>("/files/{path:^.*$}");
+    module.not_found::<NotFoundHook>();
+    let mut server: Server = Server::default();
+    server.response_middleware::<ResponseMiddleware>();
+    server.route::<TestSendRoute>("/");
+    server.mount("/module", module).unwrap();
+    let (server_control_hook, address): (ServerControlHook, SocketAddr) =
+        run_server(&mut server, ServerConfig::default()).await;
+    for path in ["/module/users", "/module/items/1", "/module/files/a/b.txt"] {
+        let response: String = send_http_request(
+            address,
+            &format!("GET {path} HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n"),
+        )
+        .await;
+        assert!(response.starts_with("http/1.1 200"));
+        assert!(response.ends_with("high,"));
+    }
+    let response: String = send_http_request(
+        address,
+        "GET / HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n",
+    )
+    .await;
+    assert!(response.starts_with("http/1.1 200"));
+    assert!(!response.contains("high,"));
+    let response: String = send_http_request(
+        address,
+        "GET /module/missing HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n",
+    )
+    .await;
+    assert!(response.starts_with("http/1.1 404"));
+    assert!(response.ends_with("no route for /module/missing"));
+    let response: String = send_http_request(
+        address,
+        "GET /missing HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n",
+    )
+    .await;
+    assert!(response.starts_with("http/1.1 404"));
+    assert!(!response.contains("no route for"));
+    server_control_hook.shutdown().await;
+    server_control_hook.wait().await;
+}
+
+#[test]
+fn mount_server_duplicate_route() {
+    let mut module: Server = Server::default();
+    module.route::<TestSendRoute>("/other");
+    module.route::<TestSendRoute>("/users");
+    let mut server: Server = Server::default();
+    server.route::<TestSendRoute>("/module/users");
+    let result: Result<&mut Server, RouteError> = server.mount("/module", module);
+    assert!(matches!(result, Err(RouteError::DuplicatePattern(_))));
+    assert_eq!(server.get_route_matcher().get_static_route().len(), 1);
+}
+
+#[tokio::test]
+async fn graceful_shutdown() {
+    let mut server: Server = Server::default();
+    server.response_middleware::<ResponseMiddleware>();
+    server.route::<SlowRoute>("/slow");
+    let (server_control_hook, address): (ServerControlHook, SocketAddr) =
+        run_server(&mut server, ServerConfig::default()).await;
+    let request: JoinHandle<String> = spawn(send_http_request(
+        address,
+        "GET /slow HTTP/1.1\r\nHost: localhost\r\nConnection: keep-alive\r\n\r\n",
+    ));
+    sleep(Duration::from_millis(100)).await;
+    let start: Instant = Instant::now();
+    server_control_hook.shutdown().await;
+    server_control_hook.wait().await;
+    assert!(start.elapsed() >= Duration::from_millis(300));
+    let response: String = request.await.unwrap();
+    assert!(response.starts_with("http/1.1 200"));
+    assert!(response.contains("connection:close"));
+    assert!(response.ends_with("slow"));
+}
+
+#[tokio::test]
+async fn graceful_shutdown_idle_connection() {
+    let mut server: Server = Server::default();
+    server.response_middleware::<ResponseMiddleware>();
+    server.route::<TestSendRoute>("/");
+    let (server_control_hook, address): (ServerControlHook, SocketAddr) =
+        run_server(&mut server, ServerConfig::default()).await;
+    let mut stream: TcpStream = TcpStream::connect(address).await.unwrap();
+    sleep(Duration::from_millis(100)).await;
+    let start: Instant = Instant::now();
+    server_control_hook.shutdown().await;
+    server_control_hook.wait().await;
+    assert!(start.elapsed() < Duration::from_secs(1));
+    let mut response: Vec<u8> = Vec::new();
+    stream.read_to_end(&mut response).await.unwrap();
+    assert!(response.is_empty());
+}
+
+#[tokio::test]
+async fn graceful_shutdown_partial_request() {
+    let mut server: Server = Server::default();
+    server.response_middleware::<ResponseMiddleware>();
+    server.route::<EchoBodyRoute>("/echo");
+    let (server_control_hook, address): (ServerControlHook, SocketAddr) =
+        run_server(&mut server, ServerConfig::default()).await;
+    let mut stream: TcpStream = TcpStream::connect(address).await.unwrap();
+    stream
+        .write_all(b"POST /echo HTTP/1.1\r\nHost: localhost\r\nContent-Length: 5\r\n\r\nfirst")
+        .await
+        .unwrap();
+    let mut response: Vec<u8> = Vec::new();
+    while !response.ends_with(b"first") {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        let length: usize = stream.read(&mut buffer).await.unwrap();
+        assert!(length > 0);
+        response.extend_from_slice(&buffer[..length]);
+    }
+    stream
+        .write_all(b"POST /echo HTTP/1.1\r\nHost: localhost\r\nContent-Length: 10\r\n\r\nhello")
+        .await
+        .unwrap();
+    sleep(Duration::from_millis(100)).await;
+    server_control_hook.shutdown().await;
+    sleep(Duration::from_millis(100)).await;
+    stream.write_all(b"world").await.unwrap();
+    let mut response: Vec<u8> = Vec::new();
+    stream.read_to_end(&mut response).await.unwrap();
+    let response: String = String::from_utf8_lossy(&response).to_lowercase();
+    assert!(response.starts_with("http/1.1 200"));
+    assert!(response.contains("connection:close"));
+    assert!(response.ends_with("helloworld"));
+    server_control_hook.wait().await;
+}
+
+#[tokio::test]
+async fn graceful_shutdown_timeout() {
+    let mut server: Server = Server::default();
+    let mut server_config: ServerConfig = ServerConfig::default();
+    server_config.set_shutdown_timeout_ms(Some(200));
+    server.response_middleware::<ResponseMiddleware>();
+    server.route::<HangRoute>("/hang");
+    let (server_control_hook, address): (ServerControlHook, SocketAddr) =
+        run_server(&mut server, server_config).await;
+    let request: JoinHandle<String> = spawn(send_http_request(
+        address,
+        "GET /hang HTTP/1.1\r\nHost: localhost\r\nConnection: keep-alive\r\n\r\n",
+    ));
+    sleep(Duration::from_millis(100)).await;
+    let start: Instant = Instant::now();
+    server_control_hook.shutdown().await;
+    server_control_hook.wait().await;
+    assert!(start.elapsed() < Duration::from_secs(5));
+    assert!(request.await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn connection_timeout() {
+    let mut server: Server = Server::default();
+    let mut server_config: ServerConfig = ServerConfig::default();
+    server_config
+        .set_keep_alive_timeout_ms(Some(200))
+        .set_request_header_timeout_ms(Some(200))
+        .set_request_read_timeout_ms(Some(200));
+    server.request_error::<ConnectionTimeoutHook>();
+    server.response_middleware::<ResponseMiddleware>();
+    server.route::<TestSendRoute>("/");
+    let (server_control_hook, address): (ServerControlHook, SocketAddr) =
+        run_server(&mut server, server_config).await;
+    let response: String = send_http_request(address, "").await;
+    assert!(response.starts_with("http/1.1 408"));
+    assert!(response.ends_with("requestheader"));
+    let response: String = send_http_request(
+        address,
+        "GET / HTTP/1.1\r\nHost: localhost\r\nConnection: keep-alive\r\n\r\n",
+    )
+    .await;
+    assert!(response.starts_with("http/1.1 200"));
+    assert!(response.ends_with("keepalive"));
+    let response: String = send_http_request(address, "GET / HTTP/1.1\r\n").await;
+    assert!(response.starts_with("http/1.1 408"));
+    assert!(response.ends_with("requestheader"));
+    let response: String = send_http_request(
+        address,
+        "POST / HTTP/1.1\r\nHost: localhost\r\nContent-Length: 10\r\n\r\nabc",
+    )
+    .await;
+    assert!(response.starts_with("http/1.1 408"));
+    assert!(response.ends_with("requestread"));
+    server_control_hook.shutdown().await;
+    server_control_hook.wait().await;
+}
+
+#[tokio::test]
+async fn max_connections() {
+    let mut server: Server = Server::default();
+    let mut server_config: ServerConfig = ServerConfig::default();
+    server_config.set_max_connections(Some(1));
+    server.response_middleware::<ResponseMiddleware>();
+    server.route::<SlowRoute>("/slow");
+    let (server_control_hook, address): (ServerControlHook, SocketAddr) =
+        run_server(&mut server, server_config).await;
+    let request: &str = "GET /slow HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n";
+    let first_request: JoinHandle<String> = spawn(send_http_request(address, request));
+    sleep(Duration::from_millis(100)).await;
+    assert_eq!(server_control_hook.connection_count(), 1);
+    let start: Instant = Instant::now();
+    let response: String = send_http_request(address, request).await;
+    assert!(start.elapsed() >= Duration::from_millis(800));
+    assert!(response.ends_with("slow"));
+    assert!(first_request.await.unwrap().ends_with("slow"));
+    server_control_hook.shutdown().await;
+    server_control_hook.wait().await;
+    assert_eq!(server_control_hook.connection_count(), 0);
+}
+
+#[tokio::test]
+async fn max_connections_zero_unlimited() {
+    let mut server: Server = Server::default();
+    let mut server_config: ServerConfig = ServerConfig::default();
+    server_config.set_max_connections(Some(0));
+    server.response_middleware::<ResponseMiddleware>();
+    server.route::<TestSendRoute>("/test");
+    let (server_control_hook, address): (ServerControlHook, SocketAddr) =
+        run_server(&mut server, server_config).await;
+    let response: String = timeout(
+        Duration::from_secs(5),
+        send_http_request(
+            address,
+            "GET /test HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n",
+        ),
+    )
+    .await
+    .unwrap();
+    assert!(response.starts_with("http/1.1 200"));
+    server_control_hook.shutdown().await;
+    server_control_hook.wait().await;
+}
+
+#[tokio::test]
+async fn multiple_addresses() {
+    let mut server: Server = Server::default();
+    let mut server_config: ServerConfig = ServerConfig::default();
+    server_config.add_address("127.0.0.1:0");
+    server.response_middleware::<ResponseMiddleware>();
+    server.route::<TestSendRoute>("/");
+    let (server_control_hook, address): (ServerControlHook, SocketAddr) =
+        run_server(&mut server, server_config).await;
+    let local_addresses: Vec<SocketAddr> = server_control_hook.get_local_addresses().clone();
+    assert_eq!(local_addresses.len(), 2);
+    assert_eq!(local_addresses[0], address);
+    assert_ne!(local_addresses[0], local_addresses[1]);
+    let request: &str = "GET / HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n";
+    for address in local_addresses.iter() {
+        assert_ne!(address.port(), 0);
+        let response: String = send_http_request(*address, request).await;
+        assert!(response.starts_with("http/1.1 200"));
+    }
+    server_control_hook.shutdown().await;
+    server_control_hook.wait().await;
+    for address in local_addresses.iter() {
+        assert!(TcpStream::connect(address).await.is_err());
+    }
+}
+
+#[tokio::test]
+async fn multiple_address_families() {
+    if let Err(error) = std::net::TcpListener::bind("[::1]:0")
+        && matches!(
+            error.kind(),
+            ErrorKind::AddrNotAvailable | ErrorKind::Unsupported
+        )
+    {
+        return;
+    }
+    let port: u16 = std::net::TcpListener::bind("0.0.0.0:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port();
+    let mut server: Server = Server::default();
+    let mut server_config: ServerConfig = ServerConfig::default();
+    server_config
+        .set_address(format!("0.0.0.0:{port}"))
+        .add_address(format!("[::]:{port}"));
+    server.server_config(server_config);
+    server.response_middleware::<ResponseMiddleware>();
+    server.route::<TestSendRoute>("/");
+    let server_control_hook: ServerControlHook = server.run().await.unwrap();
+    let local_addresses: Vec<SocketAddr> = server_control_hook.get_local_addresses().clone();
+    assert_eq!(local_addresses.len(), 2);
+    assert!(local_addresses[0].is_ipv4());
+    assert!(local_addresses[1].is_ipv6());
+    let request: &str = "GET / HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n";
+    for address in [format!("127.0.0.1:{port}"), format!("[::1]:{port}")] {
+        let response: String = send_http_request(address.parse().unwrap(), request).await;
+        assert!(response.starts_with("http/1.1 200"));
+    }
+    server_control_hook.shutdown().await;
+    server_control_hook.wait().await;
+}
+
+#[tokio::test]
+async fn unix_address_unsupported() {
+    let mut server: Server = Server::default();
+    let mut server_config: ServerConfig = ServerConfig::default();
+    server_config.set_address("unix:/tmp/hyperlane.sock");
+    server.server_config(server_config);
+    assert_eq!(
+        server.run().await.err(),
+        Some(ServerError::UnsupportedAddress(
+            "unix:/tmp/hyperlane.sock".to_string()
+        ))
+    );
+}
+
+#[tokio::test]
+async fn run_with_listener() {
+    let tcp_listener: std::net::TcpListener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let listener_address: SocketAddr = tcp_listener.local_addr().unwrap();
+    let mut server: Server = Server::default();
+    server.response_middleware::<ResponseMiddleware>();
+    server.route::<TestSendRoute>("/");
+    let server_control_hook: ServerControlHook =
+        server.run_with_listener(tcp_listener).await.unwrap();
+    let address: SocketAddr = server_control_hook.get_local_address();
+    assert_eq!(address, listener_address);
+    let response: String = send_http_request(
+        address,
+        "GET / HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n",
+    )
+    .await;
+    assert!(response.starts_with("http/1.1 200"));
+    server_control_hook.shutdown().await;
+    server_control_hook.wait().await;
+}
+
+#[tokio::test]
+async fn proxy_protocol() {
+    let mut server: Server = Server::default();
+    let mut server_config: ServerConfig = ServerConfig::default();
+    server_config.set_proxy_protocol(Some(true));
+    server.response_middleware::<ResponseMiddleware>();
+    server.route::<ClientAddressRoute>("/");
+    server.accept_error::<ProxyProtocolErrorRecorder>();
+    let (server_control_hook, address): (ServerControlHook, SocketAddr) =
+        run_server(&mut server, server_config).await;
+    let request: &str = "GET / HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n";
+    let response: String = send_http_request(
+        address,
+        &format!("PROXY TCP4 192.0.2.1 198.51.100.1 56324 443\r\n{request}"),
+    )
+    .await;
+    assert!(response.starts_with("http/1.1 200"));
+    assert!(response.ends_with("some(192.0.2.1:56324)|some(198.51.100.1:443)"));
+    let response: String = send_http_request(
+        address,
+        &format!(
+            "\r\n\r\n\0\r\nQUIT\n\x21\x11\x00\x0C\x0A\x00\x00\x01\x0A\x00\x00\x02\x12\x34\x00\x50{request}"
+        ),
+    )
+    .await;
+    assert!(response.starts_with("http/1.1 200"));
+    assert!(response.ends_with("some(10.0.0.1:4660)|some(10.0.0.2:80)"));
+    let response: String = send_http_request(
+        address,
+        &format!("\r\n\r\n\0\r\nQUIT\n\x20\x00\x00\x00{request}"),
+    )
+    .await;
+    assert!(response.ends_with("none|none"));
+    assert!(is_connection_rejected(address, request).await);
+    assert!(
+        is_connection_rejected(
+            address,
+            &format!("PROXY TCP4 192.0.2.1 not-an-address 56324 443\r\n{request}"),
+        )
+        .await
+    );
+    let response: String = send_http_request(
+        address,
+        &format!("\r\n\r\n\0\r\nQUIT\n\x21\x31\x00\x04\x00\x00\x00\x00{request}"),
+    )
+    .await;
+    assert!(response.ends_with("none|none"));
+    server_control_hook.shutdown().await;
+    server_control_hook.wait().await;
+    let error_list: Vec<ProxyProtocolError> = PROXY_PROTOCOL_ERROR_LIST.lock().unwrap().clone();
+    assert_eq!(error_list.len(), 2);
+    assert_eq!(error_list[0], ProxyProtocolError::Missing);
+    assert!(matches!(error_list[1], ProxyProtocolError::Malformed(_)));
+}
+
+#[test]
+fn proxy_header_parse() {
+    let header: ProxyHeader =
+        ProxyHeader::parse_v1(b"PROXY TCP6 2001:db8::1 2001:db8::2 1000 2000\r\n").unwrap();
+    assert_eq!(
+        header.try_get_source_address(),
+        &Some("[2001:db8::1]:1000".parse().unwrap())
+    );
+    assert_eq!(
+        header.try_get_destination_address(),
+        &Some("[2001:db8::2]:2000".parse().unwrap())
+    );
+    assert_eq!(
+        ProxyHeader::parse_v1(b"PROXY UNKNOWN\r\n").unwrap(),
+        ProxyHeader::default()
+    );
+    assert!(ProxyHeader::parse_v1(b"PROXY TCP4 2001:db8::1 192.0.2.1 1 2\r\n").is_err());
+    assert!(ProxyHeader::parse_v2(0x21, 0x11, &[0; 4]).is_err());
+    assert!(ProxyHeader::parse_v2(0x11, 0x11, &[0; 12]).is_err());
+    assert_eq!(
+        ProxyHeader::parse_v2(0x21, 0x31, &[0; 216]).unwrap(),
+        ProxyHeader::default()
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn inherited_listeners_without_socket_activation() {
+    assert!(Server::inherited_listeners().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn run_with_no_listener() {
+    let server: Server = Server::default();
+    assert!(matches!(
+        server.run_with_listeners(Vec::new()).await,
+        Err(ServerError::TcpBind(_))
+    ));
+}
+
+#[cfg(target_os = "linux")]
+#[tokio::test]
+async fn reuseport_acceptors() {
+    let mut server: Server = Server::default();
+    let mut server_config: ServerConfig = ServerConfig::default();
+    server_config.set_acceptors(Some(4));
+    server.response_middleware::<ResponseMiddleware>();
+    server.route::<TestSendRoute>("/");
+    let (server_control_hook, address): (ServerControlHook, SocketAddr) =
+        run_server(&mut server, server_config).await;
+    let local_addresses: Vec<SocketAddr> = server_control_hook.get_local_addresses().clone();
+    assert_eq!(local_addresses.len(), 4);
+    assert!(
+        local_addresses
+            .iter()
+            .all(|local_address: &SocketAddr| *local_address == address)
+    );
+    for _ in 0..8 {
+        let response: String = send_http_request(
+            address,
+            "GET / HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n",
+        )
+        .await;
+        assert!(response.starts_with("http/1.1 200"));
+    }
+    server_control_hook.shutdown().await;
+    server_control_hook.wait().await;
+}
+
+#[tokio::test]
+async fn socket_options() {
+    let mut server: Server = Server::default();
+    let mut server_config: ServerConfig = ServerConfig::default();
+    server_config
+        .set_address("127.0.0.1:0")
+        .set_additional_addresses(vec!["[::1]:0".to_string()])
+        .set_nodelay(Some(true))
+        .set_backlog(Some(128))
+        .set_reuseaddr(Some(true))
+        .set_ipv6_only(Some(true))
+        .set_keepalive_idle_ms(Some(60000))
+        .set_keepalive_interval_ms(Some(10000))
+        .set_keepalive_retries(Some(5))
+        .set_recv_buffer_size(Some(65536))
+        .set_send_buffer_size(Some(65536))
+        .set_linger_secs(Some(1));
+    #[cfg(target_os = "linux")]
+    server_config.set_fastopen(Some(16));
+    server.server_config(server_config);
+    server.response_middleware::<ResponseMiddleware>();
+    server.route::<SocketOptionsRoute>("/");
+    let tcp_listener_list: Vec<std::net::TcpListener> = server.bind().await.unwrap();
+    assert_eq!(tcp_listener_list.len(), 2);
+    let ipv4_listener: SockRef = SockRef::from(&tcp_listener_list[0]);
+    assert!(ipv4_listener.recv_buffer_size().unwrap() >= 65536);
+    let ipv6_listener: SockRef = SockRef::from(&tcp_listener_list[1]);
+    assert!(ipv6_listener.only_v6().unwrap());
+    assert!(ipv6_listener.recv_buffer_size().unwrap() >= 65536);
+    let server_control_hook: ServerControlHook =
+        server.run_with_listeners(tcp_listener_list).await.unwrap();
+    let address: SocketAddr = server_control_hook.get_local_address();
+    let response: String = send_http_request(
+        address,
+        "GET / HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n",
+    )
+    .await;
+    assert!(response.starts_with("http/1.1 200"));
+    let socket_options: Vec<&str> = response.rsplit("\r\n").next().unwrap().split(',').collect();
+    assert_eq!(socket_options[..3], ["true", "true", "some(1)"]);
+    assert!(socket_options[3].parse::<usize>().unwrap() >= 65536);
+    server_control_hook.shutdown().await;
+    server_control_hook.wait().await;
+}
+
 #[tokio::test]
 async fn main() {
     let mut server: Server = Server::default();
     let mut server_config: ServerConfig = ServerConfig::default();
     server_config
-        .set_address(Server::format_bind_address(DEFAULT_HOST, 80))
+        .set_address(Server::format_bind_address(DEFAULT_HOST, 0))
         .set_nodelay(Some(false));
     server.server_config(server_config);
     server.task_panic::<TaskPanicHook>();
@@ -168,6 +910,7 @@ async fn main() {
     server.route::<SseRoute>("/sse");
     server.route::<WebsocketRoute>("/websocket");
     server.route::<GetAllRoutes>("/get/all/routes");
+    server.route::<GetRouteList>("/get/route/list");
     server.route::<DynamicRoute>("/dynamic/{routing}");
     server.route::<DynamicRoute>("/regex/{file:^.*$}");
     let _: Result<(), Server> = SERVER_REF.set(server.clone());
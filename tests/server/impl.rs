@@ -64,6 +64,7 @@ impl ServerHook for RequestErrorHook {
 }
 
 impl ServerHook for RequestMiddleware {
+    #[allow(unused_assignments)]
     async fn new(stream: &mut Stream, _: &mut Context) -> Self {
         let mut socket_addr: String = String::new();
 
@@ -238,6 +239,7 @@ impl ServerHook for GetAllRoutes {
         Self
     }
 
+    #[allow(deprecated)]
     async fn handle(self, _: &mut Stream, ctx: &mut Context) -> Status {
         if let Some(server) = SERVER_REF.get() {
             let route_matcher: &RouteMatcher = server.get_route_matcher();
@@ -260,3 +262,186 @@ impl ServerHook for GetAllRoutes {
         Status::Continue
     }
 }
+
+impl ServerHook for GetRouteList {
+    async fn new(_: &mut Stream, _: &mut Context) -> Self {
+        Self
+    }
+
+    async fn handle(self, _: &mut Stream, ctx: &mut Context) -> Status {
+        if let Some(server) = SERVER_REF.get() {
+            let route_matcher: &RouteMatcher = server.get_route_matcher();
+            let mut response_body: String = String::new();
+            for (_, path, _) in route_matcher.get_route_list() {
+                response_body.push_str(&format!("Route: {path}\n"));
+            }
+            ctx.get_mut_response().set_body(&response_body);
+        }
+        Status::Continue
+    }
+}
+
+impl ServerHook for NotFoundHook {
+    async fn new(_: &mut Stream, _: &mut Context) -> Self {
+        Self
+    }
+
+    async fn handle(self, _: &mut Stream, ctx: &mut Context) -> Status {
+        let response_body: String = format!("No route for {}", ctx.get_request().get_path());
+        ctx.get_mut_response().set_body(&response_body);
+        Status::Continue
+    }
+}
+
+impl ServerHook for HighOrderMiddleware {
+    async fn new(_: &mut Stream, _: &mut Context) -> Self {
+        Self
+    }
+
+    async fn handle(self, _: &mut Stream, ctx: &mut Context) -> Status {
+        let mut response_body: Vec<u8> = ctx.get_response().get_body().clone();
+        response_body.extend_from_slice(b"high,");
+        ctx.get_mut_response().set_body(&response_body);
+        Status::Continue
+    }
+}
+
+impl ServerHook for LowOrderMiddleware {
+    async fn new(_: &mut Stream, _: &mut Context) -> Self {
+        Self
+    }
+
+    async fn handle(self, _: &mut Stream, ctx: &mut Context) -> Status {
+        let mut response_body: Vec<u8> = ctx.get_response().get_body().clone();
+        response_body.extend_from_slice(b"low,");
+        ctx.get_mut_response().set_body(&response_body);
+        Status::Continue
+    }
+}
+
+impl ServerHook for SlowRoute {
+    async fn new(_: &mut Stream, _: &mut Context) -> Self {
+        Self
+    }
+
+    async fn handle(self, _: &mut Stream, ctx: &mut Context) -> Status {
+        sleep(Duration::from_millis(500)).await;
+        ctx.get_mut_response().set_body("slow");
+        Status::Continue
+    }
+}
+
+impl ServerHook for HangRoute {
+    async fn new(_: &mut Stream, _: &mut Context) -> Self {
+        Self
+    }
+
+    async fn handle(self, _: &mut Stream, _: &mut Context) -> Status {
+        sleep(Duration::from_secs(60)).await;
+        Status::Continue
+    }
+}
+
+impl ServerHook for ClientAddressRoute {
+    async fn new(_: &mut Stream, _: &mut Context) -> Self {
+        Self
+    }
+
+    async fn handle(self, _: &mut Stream, ctx: &mut Context) -> Status {
+        let body: String = format!(
+            "{:?}|{:?}",
+            ctx.try_get_client_address(),
+            ctx.try_get_destination_address()
+        );
+        ctx.get_mut_response().set_body(body);
+        Status::Continue
+    }
+}
+
+impl ServerHook for EchoBodyRoute {
+    async fn new(_: &mut Stream, _: &mut Context) -> Self {
+        Self
+    }
+
+    async fn handle(self, _: &mut Stream, ctx: &mut Context) -> Status {
+        let body: Vec<u8> = ctx.get_request().get_body().clone();
+        ctx.get_mut_response().set_body(&body);
+        Status::Continue
+    }
+}
+
+impl ServerHook for SocketOptionsRoute {
+    async fn new(_: &mut Stream, _: &mut Context) -> Self {
+        Self
+    }
+
+    async fn handle(self, stream: &mut Stream, ctx: &mut Context) -> Status {
+        let socket: SockRef = SockRef::from(stream.get_stream());
+        let linger_secs: Option<u64> = socket
+            .linger()
+            .unwrap()
+            .map(|linger: Duration| linger.as_secs());
+        let response_body: String = format!(
+            "{},{},{:?},{}",
+            socket.tcp_nodelay().unwrap(),
+            socket.keepalive().unwrap(),
+            linger_secs,
+            socket.recv_buffer_size().unwrap()
+        );
+        ctx.get_mut_response().set_body(&response_body);
+        Status::Continue
+    }
+}
+
+impl ServerHook for ConnectionTimeoutHook {
+    async fn new(_: &mut Stream, _: &mut Context) -> Self {
+        Self
+    }
+
+    async fn handle(self, stream: &mut Stream, ctx: &mut Context) -> Status {
+        let response_body: String = ctx
+            .try_get_connection_timeout()
+            .map(|connection_timeout| connection_timeout.to_string())
+            .unwrap_or_default();
+        let data: Vec<u8> = ctx
+            .get_mut_response()
+            .set_version(HttpVersion::Http1_1)
+            .set_status_code(HttpStatus::RequestTimeout.code())
+            .set_body(response_body)
+            .build();
+        if stream.try_send(data).await.is_err() {
+            stream.set_closed(true);
+            return Status::Reject;
+        }
+        Status::Continue
+    }
+}
+
+impl AcceptErrorHook for AcceptErrorLogger {
+    async fn new(_: &AcceptError) -> Self {
+        Self
+    }
+
+    async fn handle(self, error: &AcceptError) -> Status {
+        if matches!(error, AcceptError::Fatal(_)) {
+            return Status::Reject;
+        }
+        Status::Continue
+    }
+}
+
+impl AcceptErrorHook for ProxyProtocolErrorRecorder {
+    async fn new(_: &AcceptError) -> Self {
+        Self
+    }
+
+    async fn handle(self, error: &AcceptError) -> Status {
+        if let AcceptError::ProxyProtocol(error) = error {
+            PROXY_PROTOCOL_ERROR_LIST
+                .lock()
+                .unwrap()
+                .push(error.clone());
+        }
+        Status::Continue
+    }
+}
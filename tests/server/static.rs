@@ -1,3 +1,18 @@
 use super::*;
 
 pub(crate) static SERVER_REF: OnceLock<Server> = OnceLock::new();
+
+pub(crate) static PROXY_PROTOCOL_ERROR_LIST: Mutex<Vec<ProxyProtocolError>> =
+    Mutex::new(Vec::new());
+
+inventory::submit! {
+    HookType::Route("/inventory", Hook::factory::<TestSendRoute>)
+}
+
+inventory::submit! {
+    HookType::RequestMiddleware(Some(-1), Hook::factory::<LowOrderMiddleware>)
+}
+
+inventory::submit! {
+    HookType::RequestMiddleware(Some(10), Hook::factory::<HighOrderMiddleware>)
+}
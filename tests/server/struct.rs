@@ -35,3 +35,27 @@ pub(crate) struct DynamicRoute {
 }
 
 pub(crate) struct GetAllRoutes;
+
+pub(crate) struct GetRouteList;
+
+pub(crate) struct NotFoundHook;
+
+pub(crate) struct HighOrderMiddleware;
+
+pub(crate) struct LowOrderMiddleware;
+
+pub(crate) struct SlowRoute;
+
+pub(crate) struct HangRoute;
+
+pub(crate) struct ClientAddressRoute;
+
+pub(crate) struct EchoBodyRoute;
+
+pub(crate) struct SocketOptionsRoute;
+
+pub(crate) struct ConnectionTimeoutHook;
+
+pub(crate) struct AcceptErrorLogger;
+
+pub(crate) struct ProxyProtocolErrorRecorder;
@@ -0,0 +1,38 @@
+//! Socket activation reads and changes the process environment, so it is
+//! tested in its own binary, away from tests running in parallel.
+
+use hyperlane::*;
+
+#[cfg(unix)]
+#[test]
+fn inherited_listeners_environment() {
+    let other_pid: String = (std::process::id() + 1).to_string();
+    unsafe {
+        std::env::set_var(LISTEN_PID, &other_pid);
+        std::env::set_var(LISTEN_FDS, "0");
+    }
+    assert!(Server::inherited_listeners().unwrap().is_empty());
+    assert_eq!(std::env::var(LISTEN_PID).unwrap(), other_pid);
+    unsafe {
+        std::env::set_var(LISTEN_PID, std::process::id().to_string());
+        std::env::set_var(LISTEN_FDS, "invalid");
+    }
+    assert_eq!(
+        Server::inherited_listeners().unwrap_err().kind(),
+        std::io::ErrorKind::InvalidInput
+    );
+    unsafe {
+        std::env::set_var(LISTEN_FDS, "0");
+        std::env::set_var(LISTEN_FDNAMES, "");
+    }
+    assert!(Server::inherited_listeners().unwrap().is_empty());
+    assert_eq!(std::env::var(LISTEN_FDS).unwrap(), "0");
+    assert!(
+        unsafe { Server::inherited_listeners_unset_environment() }
+            .unwrap()
+            .is_empty()
+    );
+    for name in [LISTEN_FDS, LISTEN_PID, LISTEN_FDNAMES] {
+        assert!(std::env::var(name).is_err());
+    }
+}